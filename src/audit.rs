@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use crate::{display, time};
+use crate::commands::config::KubixConfig;
+use crate::commands::namespace;
+use crate::kubeconfig::KubeConfig;
+
+/// Audit log file name inside the kubix config directory (JSON lines, append-only)
+const AUDIT_FILE: &str = "audit.jsonl";
+
+/// A single audited action
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub user: String,
+    pub context: Option<String>,
+    pub namespace: Option<String>,
+    pub action: String,
+    #[serde(default)]
+    pub pod: Option<String>,
+    /// Command line, or the script path for script runs
+    #[serde(default)]
+    pub command: Option<String>,
+    /// SHA-256 of the script content, so the exact script that ran can be identified later
+    #[serde(default)]
+    pub script_sha256: Option<String>,
+    /// Exit code of the action, None if it could not be determined (e.g. killed by a signal)
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    /// The user declined the confirmation, so the action never ran
+    #[serde(default)]
+    pub cancelled: bool,
+}
+
+impl AuditEntry {
+    /// Create an entry for an action, stamped with the current time and local user
+    pub fn new(action: &str, context: Option<&str>, namespace: Option<&str>) -> Self {
+        Self {
+            timestamp: time::now_rfc3339(),
+            user: local_user(),
+            context: context.map(str::to_string),
+            namespace: namespace.map(str::to_string),
+            action: action.to_string(),
+            pod: None,
+            command: None,
+            script_sha256: None,
+            exit_code: None,
+            cancelled: false,
+        }
+    }
+
+    pub fn pod(mut self, pod: &str) -> Self {
+        self.pod = Some(pod.to_string());
+        self
+    }
+
+    pub fn command(mut self, command: &str) -> Self {
+        self.command = Some(command.to_string());
+        self
+    }
+
+    /// Record a script run by path and content hash
+    pub fn script(mut self, path: &str, content: &str) -> Self {
+        self.command = Some(path.to_string());
+        self.script_sha256 = Some(format!("{:x}", Sha256::digest(content.as_bytes())));
+        self
+    }
+
+    pub fn exit_code(mut self, exit_code: Option<i32>) -> Self {
+        self.exit_code = exit_code;
+        self
+    }
+
+    pub fn cancelled(mut self) -> Self {
+        self.cancelled = true;
+        self
+    }
+}
+
+/// Append an entry to the audit log
+/// Missing context and namespace are filled in from the kubeconfig, so entries always
+/// show where the action really ran. Failing to write is reported but never blocks the action.
+pub fn record(entry: AuditEntry) {
+    let entry = with_resolved_target(entry);
+    if let Err(err) = append(&entry) {
+        display::print_warning(&format!("Failed to write audit log: {}", err));
+    }
+}
+
+/// Read all entries from the audit log, oldest first (unreadable lines are skipped)
+pub fn load() -> Result<Vec<AuditEntry>, String> {
+    let path = audit_path().ok_or("Could not determine the kubix config directory")?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Path of the audit log file
+pub fn audit_path() -> Option<PathBuf> {
+    KubixConfig::get_config_dir().map(|dir| dir.join(AUDIT_FILE))
+}
+
+fn append(entry: &AuditEntry) -> Result<(), String> {
+    let path = audit_path().ok_or("Could not determine the kubix config directory")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+
+    let line = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Fill in the current context and the context's default namespace when not given explicitly
+fn with_resolved_target(mut entry: AuditEntry) -> AuditEntry {
+    if entry.context.is_some() && entry.namespace.is_some() {
+        return entry;
+    }
+    let Ok(kubeconfig) = KubeConfig::load() else {
+        return entry;
+    };
+
+    if entry.context.is_none() && !kubeconfig.current_context.is_empty() {
+        entry.context = Some(kubeconfig.current_context.clone());
+    }
+    if entry.namespace.is_none() {
+        let namespace = entry
+            .context
+            .as_deref()
+            .and_then(|context| kubeconfig.context(context))
+            .and_then(|context| context.namespace.clone())
+            .filter(|namespace| !namespace.is_empty())
+            .unwrap_or_else(|| namespace::DEFAULT_NAMESPACE.to_string());
+        entry.namespace = Some(namespace);
+    }
+    entry
+}
+
+/// Name of the local user running kubix
+fn local_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::{display, time};
+use crate::commands::config::KubixConfig;
+use crate::commands::namespace::DEFAULT_NAMESPACE;
+use crate::kubeconfig::KubeConfig;
+
+/// Set by `--no-cache` - always fetch fresh names (the fresh result still refreshes the cache)
+static CACHE_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// On-disk cache of resource names (contexts, namespaces, pods), stored next to the kubix config
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Cache {
+    #[serde(default)]
+    pub entries: BTreeMap<String, CacheEntry>,
+}
+
+/// Cached names and when they were fetched (unix seconds)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CacheEntry {
+    pub updated: i64,
+    pub names: Vec<String>,
+}
+
+impl Cache {
+    /// Load the cache using confy, falling back to an empty cache
+    pub fn load() -> Self {
+        confy::load("kubix", Some("cache")).unwrap_or_default()
+    }
+
+    /// Save the cache using confy
+    pub fn save(&self) -> Result<(), String> {
+        confy::store("kubix", Some("cache"), self)
+            .map_err(|err| format!("Failed to save cache: {}", err))
+    }
+}
+
+/// Bypass the cache for this run
+pub fn disable() {
+    CACHE_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Cache key for the context list
+pub fn contexts_key() -> String {
+    "contexts".to_string()
+}
+
+/// Cache key for the namespaces of a context (None = current context)
+pub fn namespaces_key(context: Option<&str>) -> String {
+    let context = context
+        .map(str::to_string)
+        .unwrap_or_else(|| KubeConfig::load().unwrap_or_default().current_context);
+    format!("namespaces/{}", context)
+}
+
+/// Cache key for the pod names of a context and namespace (None = current context / its default namespace)
+pub fn pods_key(context: Option<&str>, namespace: Option<&str>) -> String {
+    let (context, namespace) = effective_scope(context, namespace);
+    format!("pods/{}/{}", context, namespace)
+}
+
+/// Cache key for the "namespace/name" entries of pods in every namespace of a context
+pub fn all_namespaces_pods_key(context: Option<&str>) -> String {
+    let (context, _) = effective_scope(context, Some(""));
+    format!("pods/{}/*", context)
+}
+
+/// Names matching a filter, served from the cache while fresh
+/// When cached names match nothing they may be stale, so they are fetched again before giving up
+pub fn matching_names(
+    key: &str,
+    fetch: impl Fn() -> Result<Vec<String>, String>,
+    filter: impl Fn(&str) -> bool,
+) -> Result<Vec<String>, String> {
+    if let Some(names) = fresh_names(key) {
+        let matches: Vec<String> = names.into_iter().filter(|name| filter(name)).collect();
+        if !matches.is_empty() {
+            return Ok(matches);
+        }
+    }
+
+    fresh_matching_names(key, fetch, filter)
+}
+
+/// Names matching a filter, always fetched fresh - for destructive commands that must not act on stale names
+/// The fresh result still refreshes the cache
+pub fn fresh_matching_names(
+    key: &str,
+    fetch: impl Fn() -> Result<Vec<String>, String>,
+    filter: impl Fn(&str) -> bool,
+) -> Result<Vec<String>, String> {
+    let names = fetch()?;
+    store(key, &names);
+    Ok(names.into_iter().filter(|name| filter(name)).collect())
+}
+
+/// Forget cached names, e.g. after a cached pod turned out to be gone
+pub fn invalidate(key: &str) {
+    let mut cache = Cache::load();
+    if cache.entries.remove(key).is_some()
+        && let Err(err) = cache.save() {
+        display::print_warning(&err);
+    }
+}
+
+/// Remove all cached names
+pub fn clear() -> Result<usize, String> {
+    let mut cache = Cache::load();
+    let count = cache.entries.len();
+    cache.entries.clear();
+    cache.save()?;
+    Ok(count)
+}
+
+/// Cached names for a key if caching is enabled and the entry is within the TTL
+fn fresh_names(key: &str) -> Option<Vec<String>> {
+    if CACHE_DISABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    let ttl = KubixConfig::load().settings.cache_ttl_seconds as i64;
+    if ttl == 0 {
+        return None;
+    }
+
+    Cache::load()
+        .entries
+        .remove(key)
+        .filter(|entry| time::now_seconds() - entry.updated < ttl)
+        .map(|entry| entry.names)
+}
+
+/// Remember freshly fetched names
+fn store(key: &str, names: &[String]) {
+    if KubixConfig::load().settings.cache_ttl_seconds == 0 {
+        return;
+    }
+    let mut cache = Cache::load();
+    cache.entries.insert(key.to_string(), CacheEntry {
+        updated: time::now_seconds(),
+        names: names.to_vec(),
+    });
+    if let Err(err) = cache.save() {
+        display::print_warning(&err);
+    }
+}
+
+/// The context and namespace kubectl will really use, read from the local kubeconfig
+fn effective_scope(context: Option<&str>, namespace: Option<&str>) -> (String, String) {
+    if let (Some(context), Some(namespace)) = (context, namespace) {
+        return (context.to_string(), namespace.to_string());
+    }
+
+    let kubeconfig = KubeConfig::load().unwrap_or_default();
+    let context = context
+        .map(str::to_string)
+        .unwrap_or_else(|| kubeconfig.current_context.clone());
+    let namespace = namespace
+        .map(str::to_string)
+        .or_else(|| kubeconfig.context(&context).and_then(|entry| entry.namespace.clone()))
+        .filter(|namespace| !namespace.is_empty())
+        .unwrap_or_else(|| DEFAULT_NAMESPACE.to_string());
+    (context, namespace)
+}
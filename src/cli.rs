@@ -7,6 +7,15 @@ use clap::{Parser, Subcommand, ArgGroup};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+    /// Always fetch context, namespace and pod names fresh instead of using the cache
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Remove all cached context, namespace and pod names
+    Clear,
 }
 
 #[derive(Subcommand)]
@@ -38,6 +47,31 @@ pub enum ConfigCommands {
         interpreter_path: String,
     },
     
+    /// Add or update a log highlight rule (regex to color)
+    #[command(name = "add-highlight")]
+    AddHighlight {
+        /// Regex pattern to highlight in log lines (e.g., "req-[0-9a-f]+")
+        pattern: String,
+        /// Color name or hex value (e.g., "magenta", "bright red", "#ff8800")
+        color: String,
+    },
+    
+    /// Add or update a context alias (short name usable wherever a context pattern is accepted)
+    #[command(name = "add-alias")]
+    AddAlias {
+        /// Alias to use (e.g., "prod")
+        alias: String,
+        /// Context name or pattern the alias points to
+        context: String,
+    },
+    
+    /// Protect contexts matching a pattern (typed confirmation for mutating actions)
+    #[command(name = "add-protected")]
+    AddProtected {
+        /// Context pattern - substring, or glob such as "prod-*"
+        pattern: String,
+    },
+    
     /// Remove a command nickname
     #[command(name = "remove-command")]
     RemoveCommand {
@@ -59,15 +93,110 @@ pub enum ConfigCommands {
         extension: String,
     },
     
+    /// Remove a log highlight rule
+    #[command(name = "remove-highlight")]
+    RemoveHighlight {
+        /// Regex pattern of the highlight rule to remove
+        pattern: String,
+    },
+    
+    /// Remove a context alias
+    #[command(name = "remove-alias")]
+    RemoveAlias {
+        /// Alias to remove
+        alias: String,
+    },
+    
+    /// Remove a protected context pattern
+    #[command(name = "remove-protected")]
+    RemoveProtected {
+        /// Context pattern to stop protecting
+        pattern: String,
+    },
+    
     /// List current configuration (default action)
     List,
 }
 
+#[derive(Subcommand)]
+pub enum CtxCommands {
+    /// Merge contexts, clusters and users from another kubeconfig file into your kubeconfig
+    Import {
+        /// Path to the kubeconfig file to import
+        file: String,
+        /// Replace existing entries that have the same name but different content
+        #[arg(long)]
+        overwrite: bool,
+    },
+    
+    /// Rename a context
+    Rename {
+        /// Context name, alias or pattern to rename
+        context: String,
+        /// New context name
+        new_name: String,
+    },
+    
+    /// Delete a context and prune its cluster and user if nothing else uses them
+    Delete {
+        /// Context name, alias or pattern to delete
+        context: String,
+    },
+    
+    /// Write a minimal standalone kubeconfig for a context (to stdout by default)
+    Export {
+        /// Context name, alias or pattern to export
+        context: String,
+        /// Write to a file instead of stdout
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum RolloutCommands {
+    /// Roll a deployment back to its previous revision (or --to-revision), then follow the rollout
+    Undo {
+        /// Deployment name pattern
+        pattern: String,
+        /// Revision to roll back to (see `kubix rollout <pattern> --history`)
+        #[arg(long)]
+        to_revision: Option<u64>,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Namespace (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Only show what would be rolled back
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Manage kubectl contexts - list all contexts or switch to one by pattern
+    #[command(args_conflicts_with_subcommands = true)]
     Ctx {
-        /// Context name or pattern to switch to (optional - if not provided, lists all contexts)
+        #[command(subcommand)]
+        command: Option<CtxCommands>,
+        /// Context name, alias or pattern to switch to, or "-" for the previous context (optional - if not provided, lists all contexts)
+        name: Option<String>,
+        /// Show recently used contexts
+        #[arg(long, conflicts_with = "name")]
+        history: bool,
+        /// Switch only the current shell: prints exports for `eval "$(kubix ctx <name> --shell)"`
+        #[arg(long, requires = "name")]
+        shell: bool,
+        /// Leave the shell session: prints exports for `eval "$(kubix ctx --exit-shell)"`
+        #[arg(long, conflicts_with_all = ["name", "history", "shell"])]
+        exit_shell: bool,
+    },
+    
+    /// Manage the default namespace - list namespaces or set the current context's default by pattern
+    Ns {
+        /// Namespace name or pattern to switch to, or "-" for the previous namespace (optional - if not provided, lists all namespaces)
         name: Option<String>,
     },
     
@@ -79,9 +208,39 @@ pub enum Commands {
         /// Context to use (optional, uses current context if not specified)
         #[arg(long, short)]
         context: Option<String>,
+        /// List pods from every context matching a pattern or glob (e.g. 'prod-*'), in parallel
+        #[arg(long, conflicts_with = "context")]
+        contexts: Option<String>,
         /// Namespace to list pods from (optional, uses default if not specified)
         #[arg(long, short)]
         namespace: Option<String>,
+        /// List pods from every namespace, with a namespace column
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+        /// Label selector to filter pods by (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to filter pods by (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Show extra columns: node, IP, QoS class, containers and owner
+        #[arg(long)]
+        wide: bool,
+        /// Sort pods by restarts (most first), age (newest first), name, status, or CPU/memory usage (most first)
+        #[arg(long, value_parser = ["restarts", "age", "name", "status", "cpu", "memory"])]
+        sort: Option<String>,
+        /// Only show pods with one of these statuses (e.g. Running,Pending)
+        #[arg(long, value_delimiter = ',')]
+        status: Vec<String>,
+        /// Only show pods that are not ready (completed pods are skipped)
+        #[arg(long)]
+        not_ready: bool,
+        /// Keep watching and redraw on every change, highlighting what changed
+        #[arg(long, short, conflicts_with = "contexts")]
+        watch: bool,
+        /// Show CPU and memory usage from metrics-server, with requests and limits
+        #[arg(long, conflicts_with = "watch")]
+        usage: bool,
     },
     
     /// List pods, optionally filtered by pattern (alias for pods)
@@ -92,22 +251,96 @@ pub enum Commands {
         /// Context to use (optional, uses current context if not specified)
         #[arg(long, short)]
         context: Option<String>,
+        /// List pods from every context matching a pattern or glob (e.g. 'prod-*'), in parallel
+        #[arg(long, conflicts_with = "context")]
+        contexts: Option<String>,
         /// Namespace to list pods from (optional, uses default if not specified)
         #[arg(long, short)]
         namespace: Option<String>,
+        /// List pods from every namespace, with a namespace column
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+        /// Label selector to filter pods by (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to filter pods by (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Show extra columns: node, IP, QoS class, containers and owner
+        #[arg(long)]
+        wide: bool,
+        /// Sort pods by restarts (most first), age (newest first), name, status, or CPU/memory usage (most first)
+        #[arg(long, value_parser = ["restarts", "age", "name", "status", "cpu", "memory"])]
+        sort: Option<String>,
+        /// Only show pods with one of these statuses (e.g. Running,Pending)
+        #[arg(long, value_delimiter = ',')]
+        status: Vec<String>,
+        /// Only show pods that are not ready (completed pods are skipped)
+        #[arg(long)]
+        not_ready: bool,
+        /// Keep watching and redraw on every change, highlighting what changed
+        #[arg(long, short, conflicts_with = "contexts")]
+        watch: bool,
+        /// Show CPU and memory usage from metrics-server, with requests and limits
+        #[arg(long, conflicts_with = "watch")]
+        usage: bool,
     },
 
-    /// View logs from a pod
-    #[command(name = "logs")]
+    /// Show CPU and memory usage of pods with their requests and limits (needs metrics-server)
+    Top {
+        /// Pod name pattern to filter by (optional - if not provided, shows all pods)
+        pattern: Option<String>,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Show pods from every context matching a pattern or glob (e.g. 'prod-*'), in parallel
+        #[arg(long, conflicts_with = "context")]
+        contexts: Option<String>,
+        /// Namespace to show pods from (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Show pods from every namespace, with a namespace column
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+        /// Label selector to filter pods by (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to filter pods by (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Sort pods by CPU or memory usage (most first), restarts, age, name or status
+        #[arg(long, default_value = "cpu", value_parser = ["cpu", "memory", "restarts", "age", "name", "status"])]
+        sort: String,
+    },
+
+    /// View logs from a pod, a workload (deploy/api, sts/db, job/migrate) or a label selector
+    #[command(name = "logs", group(
+        ArgGroup::new("target")
+            .args(["pod", "selector", "field_selector"])
+            .required(true)
+            .multiple(true)
+    ))]
     Logs {
-        /// Pod name or pattern to match
-        pod: String,
+        /// Pod name pattern, or workload target such as deploy/api, sts/db, ds/agent, job/migrate
+        pod: Option<String>,
+        /// Label selector to find pods (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to find pods (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Show logs from all matching pods instead of one representative pod
+        #[arg(long)]
+        all_pods: bool,
         /// Context to use (optional)
         #[arg(long, short = 'x')]
         context: Option<String>,
         /// Namespace (optional)
         #[arg(long, short)]
         namespace: Option<String>,
+        /// Find the pod in every namespace and use the namespace it lives in
+        #[arg(long, short = 'A', conflicts_with_all = ["namespace", "all_pods"])]
+        all_namespaces: bool,
         /// Follow log output
         #[arg(long, short)]
         follow: bool,
@@ -126,19 +359,42 @@ pub enum Commands {
         /// Exclude lines matching this regex pattern (used with or without --grep)
         #[arg(long, short)]
         exclude: Option<String>,
+        /// Print a statistics summary instead of the log lines
+        #[arg(long, conflicts_with = "follow")]
+        stats: bool,
+        /// Number of most frequent messages to show with --stats
+        #[arg(long, default_value_t = 10, requires = "stats")]
+        top: usize,
     },
 
-    /// View logs from a pod (alias for logs)
-    #[command(name = "log")]
+    /// View logs from a pod, a workload or a label selector (alias for logs)
+    #[command(name = "log", group(
+        ArgGroup::new("target")
+            .args(["pod", "selector", "field_selector"])
+            .required(true)
+            .multiple(true)
+    ))]
     Log {
-        /// Pod name or pattern to match
-        pod: String,
+        /// Pod name pattern, or workload target such as deploy/api, sts/db, ds/agent, job/migrate
+        pod: Option<String>,
+        /// Label selector to find pods (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to find pods (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Show logs from all matching pods instead of one representative pod
+        #[arg(long)]
+        all_pods: bool,
         /// Context to use (optional)
         #[arg(long, short = 'x')]
         context: Option<String>,
         /// Namespace (optional)
         #[arg(long, short)]
         namespace: Option<String>,
+        /// Find the pod in every namespace and use the namespace it lives in
+        #[arg(long, short = 'A', conflicts_with_all = ["namespace", "all_pods"])]
+        all_namespaces: bool,
         /// Follow log output
         #[arg(long, short)]
         follow: bool,
@@ -157,6 +413,12 @@ pub enum Commands {
         /// Exclude lines matching this regex pattern (used with or without --grep)
         #[arg(long, short)]
         exclude: Option<String>,
+        /// Print a statistics summary instead of the log lines
+        #[arg(long, conflicts_with = "follow")]
+        stats: bool,
+        /// Number of most frequent messages to show with --stats
+        #[arg(long, default_value_t = 10, requires = "stats")]
+        top: usize,
     },
 
     /// Execute command or script on a pod (defaults to bash if no command/script specified)
@@ -166,8 +428,15 @@ pub enum Commands {
             .multiple(false)
     ))]
     Exec {
-        /// Pod name or pattern to match
-        pod: String,
+        /// Pod name or pattern to match (optional with a selector)
+        #[arg(required_unless_present_any = ["selector", "field_selector"])]
+        pod: Option<String>,
+        /// Label selector to find the pod (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to find the pod (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
         /// Command to execute (can be a full command or a nickname from config)
         #[arg(long, short)]
         command: Option<String>,
@@ -177,9 +446,153 @@ pub enum Commands {
         /// Context to use (optional)
         #[arg(long, short = 'x')]
         context: Option<String>,
+        /// Run the command in every context matching a pattern or glob (e.g. 'prod-*'), in parallel
+        #[arg(long, conflicts_with_all = ["context", "script"], requires = "command")]
+        contexts: Option<String>,
+        /// Namespace (optional)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Find the pod in every namespace and use the namespace it lives in
+        #[arg(long, short = 'A', conflicts_with_all = ["namespace", "contexts"])]
+        all_namespaces: bool,
+    },
+
+    /// Show a condensed summary of a pod: status, containers, probes, volumes and recent events
+    Describe {
+        /// Pod name or pattern to match (optional with a selector)
+        #[arg(required_unless_present_any = ["selector", "field_selector"])]
+        pod: Option<String>,
+        /// Label selector to find the pod (e.g., app=web,tier!=cache)
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector to find the pod (e.g., spec.nodeName=ip-10-0-1-5)
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
         /// Namespace (optional)
         #[arg(long, short)]
         namespace: Option<String>,
+        /// Find the pod in every namespace and use the namespace it lives in
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+    },
+
+    /// Show cluster events, warnings first with repeats grouped, or follow new ones
+    Events {
+        /// Name pattern of the objects to show events for (optional - if not provided, shows all events)
+        pattern: Option<String>,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Namespace (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Show events from every namespace
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+        /// Keep printing new events as they happen
+        #[arg(long, short)]
+        watch: bool,
+    },
+
+    /// Delete pods matching a pattern, after showing them and asking for confirmation
+    Delete {
+        /// Pod name pattern - pick one or more of the matches
+        pattern: String,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Namespace (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Only show which pods would be deleted
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Rollout restart deployments matching a pattern (or sts/<pattern>, ds/<pattern>)
+    Restart {
+        /// Deployment name pattern, or a workload target such as sts/db or ds/agent
+        pattern: String,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Namespace (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Only show which workloads would be restarted
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Scale a deployment (or sts/<pattern>) matching a pattern to a replica count, or by +N / -N
+    Scale {
+        /// Deployment name pattern, or a workload target such as sts/db
+        pattern: String,
+        /// Replica count, or a change such as +2 or -1
+        #[arg(allow_hyphen_values = true)]
+        replicas: String,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Namespace (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Wait until the new replica count is ready, showing the pods
+        #[arg(long, short)]
+        wait: bool,
+        /// Only show the change
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Follow the rollout of a deployment matching a pattern, show its revisions, or roll it back
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+    Rollout {
+        #[command(subcommand)]
+        command: Option<RolloutCommands>,
+        /// Deployment name pattern
+        #[arg(required = true)]
+        pattern: Option<String>,
+        /// Context to use (optional, uses current context if not specified)
+        #[arg(long, short)]
+        context: Option<String>,
+        /// Namespace (optional, uses default if not specified)
+        #[arg(long, short)]
+        namespace: Option<String>,
+        /// Show the revisions of the deployment instead of following its rollout
+        #[arg(long)]
+        history: bool,
+    },
+
+    /// Check kubectl, the kubeconfig, cluster connectivity, credentials and RBAC permissions
+    Doctor {
+        /// Context pattern, glob or alias to check (optional - checks the current context if not provided)
+        context: Option<String>,
+    },
+
+    /// Show the local audit log of kubix actions
+    Audit {
+        /// Only show entries since a duration ago (30m, 2h, 7d), a date (2024-05-01) or an RFC3339 timestamp
+        #[arg(long)]
+        since: Option<String>,
+        /// Only show entries for contexts matching a pattern or alias (globs like "prod-*" supported)
+        #[arg(long, short = 'x')]
+        context: Option<String>,
+        /// Only show entries for pods matching a pattern
+        #[arg(long, short)]
+        pod: Option<String>,
+        /// Maximum number of (most recent) entries to show
+        #[arg(long, default_value = "50")]
+        limit: usize,
+    },
+
+    /// Manage the cache of context, namespace and pod names
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
     },
 
     /// Manage kubix configuration
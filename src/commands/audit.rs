@@ -0,0 +1,49 @@
+use crate::{display, time, utils};
+use crate::audit::{self, AuditEntry};
+use crate::commands::config::KubixConfig;
+
+/// Handle the audit command - show audit log entries filtered by time, context and pod
+pub fn handle_audit_command(since: Option<&str>, context_pattern: Option<&str>, pod_pattern: Option<&str>, limit: usize) {
+    let since_seconds = since.map(|value| {
+        time::parse_since(value).unwrap_or_else(|| {
+            display::print_error_and_exit(&format!(
+                "Invalid --since value '{}'. Use a duration like 30m, 2h, 7d, a date (2024-05-01) or an RFC3339 timestamp",
+                value
+            ));
+        })
+    });
+    
+    // An exact alias stands for its context, everything else is matched as a pattern
+    let context_pattern = context_pattern.map(|pattern| {
+        KubixConfig::load().resolve_alias(pattern).unwrap_or_else(|| pattern.to_string())
+    });
+    
+    let entries = match audit::load() {
+        Ok(entries) => entries,
+        Err(error) => display::print_error_and_exit(&format!("Error reading audit log: {}", error)),
+    };
+    
+    let mut matching: Vec<AuditEntry> = entries
+        .into_iter()
+        .filter(|entry| since_seconds.is_none_or(|since| {
+            time::parse_rfc3339(&entry.timestamp).is_some_and(|(seconds, _)| seconds >= since)
+        }))
+        .filter(|entry| context_pattern.as_deref().is_none_or(|pattern| {
+            entry.context.as_deref().is_some_and(|context| utils::matches_pattern(pattern, context))
+        }))
+        .filter(|entry| pod_pattern.is_none_or(|pattern| {
+            entry.pod.as_deref().is_some_and(|pod| utils::matches_pattern(pattern, pod))
+        }))
+        .collect();
+    
+    // Keep the most recent entries, still shown oldest first
+    let total = matching.len();
+    if total > limit {
+        matching.drain(..total - limit);
+    }
+    
+    display::print_audit_table(&matching, total);
+    if let Some(path) = audit::audit_path() {
+        display::print_info(&format!("Audit log: {}", path.display()));
+    }
+}
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use crate::{utils, display};
 use crate::cli::ConfigCommands;
+use crate::commands::context;
+use owo_colors::DynColors;
+use regex::Regex;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct KubixConfig {
@@ -11,6 +15,13 @@ pub struct KubixConfig {
     pub scripts: HashMap<String, String>,
     #[serde(default = "default_interpreters")]
     pub interpreters: HashMap<String, String>,
+    #[serde(default = "default_highlights")]
+    pub highlights: HashMap<String, String>,
+    #[serde(default = "default_aliases")]
+    pub aliases: HashMap<String, String>,
+    /// Context patterns that require typed confirmation for mutating actions
+    #[serde(default = "default_protected_contexts")]
+    pub protected_contexts: Vec<String>,
     #[serde(default = "default_settings")]
     pub settings: Settings,
 }
@@ -20,12 +31,16 @@ pub struct Settings {
     /// Time to wait before executing scripts (in seconds)
     #[serde(default = "default_script_delay")]
     pub script_delay_seconds: u64,
+    /// How long cached context, namespace and pod names stay fresh (in seconds, 0 disables the cache)
+    #[serde(default = "default_cache_ttl")]
+    pub cache_ttl_seconds: u64,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             script_delay_seconds: default_script_delay(),
+            cache_ttl_seconds: default_cache_ttl(),
         }
     }
 }
@@ -36,6 +51,9 @@ impl Default for KubixConfig {
             commands: default_commands(),
             scripts: default_scripts(),
             interpreters: default_interpreters(),
+            highlights: default_highlights(),
+            aliases: default_aliases(),
+            protected_contexts: default_protected_contexts(),
             settings: default_settings(),
         }
     }
@@ -77,6 +95,36 @@ impl KubixConfig {
         self.interpreters.get(extension).cloned()
     }
 
+    /// Resolve a context alias to the context name it stands for
+    pub fn resolve_alias(&self, alias: &str) -> Option<String> {
+        self.aliases.get(alias).cloned()
+    }
+
+    /// Aliases pointing at a context, sorted
+    pub fn aliases_for(&self, context: &str) -> Vec<String> {
+        let mut aliases: Vec<String> = self.aliases
+            .iter()
+            .filter(|(_, target)| target.as_str() == context)
+            .map(|(alias, _)| alias.clone())
+            .collect();
+        aliases.sort();
+        aliases
+    }
+
+    /// Whether a context matches one of the protected context patterns
+    pub fn is_protected_context(&self, context: &str) -> bool {
+        self.protected_contexts
+            .iter()
+            .any(|pattern| utils::matches_pattern(pattern, context))
+    }
+
+    /// Get the directory holding the config file and other kubix state
+    pub fn get_config_dir() -> Option<PathBuf> {
+        confy::get_configuration_file_path("kubix", Some("kubix"))
+            .ok()
+            .and_then(|path| path.parent().map(|dir| dir.to_path_buf()))
+    }
+
     /// Get the config file path
     pub fn get_config_path() -> String {
         match confy::get_configuration_file_path("kubix", Some("kubix")) {
@@ -114,6 +162,24 @@ pub fn handle_config_command(config_cmd: Option<&ConfigCommands>) {
         Some(ConfigCommands::RemoveInterpreter { extension }) => {
             remove_interpreter(extension);
         }
+        Some(ConfigCommands::AddHighlight { pattern, color }) => {
+            add_highlight(pattern, color);
+        }
+        Some(ConfigCommands::RemoveHighlight { pattern }) => {
+            remove_highlight(pattern);
+        }
+        Some(ConfigCommands::AddAlias { alias, context }) => {
+            add_alias(alias, context);
+        }
+        Some(ConfigCommands::RemoveAlias { alias }) => {
+            remove_alias(alias);
+        }
+        Some(ConfigCommands::AddProtected { pattern }) => {
+            add_protected(pattern);
+        }
+        Some(ConfigCommands::RemoveProtected { pattern }) => {
+            remove_protected(pattern);
+        }
     }
 }
 
@@ -126,10 +192,13 @@ pub fn show_config() {
     display::print_commands_table(&config.commands);
     display::print_scripts_table(&config.scripts);
     display::print_interpreters_table(&config.interpreters);
+    display::print_highlights_table(&config.highlights);
+    display::print_aliases_table(&config.aliases);
+    display::print_protected_contexts_table(&config.protected_contexts);
     display::print_settings_table(&config.settings);
     
-    if config.commands.is_empty() && config.scripts.is_empty() && config.interpreters.is_empty() {
-        display::print_info("No custom commands, scripts, or interpreters configured.\n");
+    if config.commands.is_empty() && config.scripts.is_empty() && config.interpreters.is_empty() && config.highlights.is_empty() && config.aliases.is_empty() && config.protected_contexts.is_empty() {
+        display::print_info("No custom commands, scripts, interpreters, highlights, aliases, or protected contexts configured.\n");
     } else {
         display::print_line("");
     }
@@ -138,9 +207,15 @@ pub fn show_config() {
         "  • kubix config add-command <nickname> <command>",
         "  • kubix config add-script <nickname> <script>", 
         "  • kubix config add-interpreter <extension> <interpreter_path>",
+        "  • kubix config add-highlight <regex> <color>",
+        "  • kubix config add-alias <alias> <context-pattern>",
+        "  • kubix config add-protected <context-pattern>",
         "  • kubix config remove-command <nickname>",
         "  • kubix config remove-script <nickname>",
-        "  • kubix config remove-interpreter <extension>\n",
+        "  • kubix config remove-interpreter <extension>",
+        "  • kubix config remove-highlight <regex>",
+        "  • kubix config remove-alias <alias>",
+        "  • kubix config remove-protected <context-pattern>\n",
     ]);
     
     display::print_info("💡 Usage:");
@@ -285,6 +360,148 @@ pub fn remove_interpreter(extension: &str) {
     }
 }
 
+/// Add a log highlight rule with confirmation if it already exists
+pub fn add_highlight(pattern: &str, color: &str) {
+    if let Err(e) = Regex::new(pattern) {
+        display::print_error_and_exit(&format!("Invalid highlight pattern '{}': {}", pattern, e));
+    }
+    if color.parse::<DynColors>().is_err() {
+        display::print_error_and_exit(&format!("Invalid color '{}'. Use a name like 'red' or 'bright magenta', or a hex value like '#ff8800'", color));
+    }
+    
+    let mut config = KubixConfig::load();
+    
+    // Check if highlight already exists
+    if let Some(existing_color) = config.highlights.get(pattern) {
+        display::print_warning(&format!("Highlight '{}' already exists: '{}'", pattern, existing_color));
+        if !utils::prompt_for_confirmation("Do you want to overwrite it?") {
+            display::print_error("Operation cancelled.");
+            return;
+        }
+    }
+    
+    // Add or update the highlight
+    config.highlights.insert(pattern.to_string(), color.to_string());
+    
+    match config.save() {
+        Ok(_) => {
+            display::print_success(&format!("Highlight '{}' added successfully", pattern));
+        }
+        Err(err) => {
+            display::print_error_and_exit(&format!("Failed to save config: {}", err));
+        }
+    }
+}
+
+/// Remove a log highlight rule
+pub fn remove_highlight(pattern: &str) {
+    let mut config = KubixConfig::load();
+    
+    if config.highlights.remove(pattern).is_some() {
+        match config.save() {
+            Ok(_) => {
+                display::print_success(&format!("Highlight '{}' removed successfully", pattern));
+            }
+            Err(err) => {
+                display::print_error_and_exit(&format!("Failed to save config: {}", err));
+            }
+        }
+    } else {
+        display::print_error(&format!("Highlight '{}' not found", pattern));
+    }
+}
+
+/// Add a context alias with confirmation if it already exists
+/// The context is given as a pattern and resolved to its full name
+pub fn add_alias(alias: &str, context_pattern: &str) {
+    let Some(context) = context::resolve_context_pattern(context_pattern) else {
+        display::print_error("Operation cancelled.");
+        return;
+    };
+    
+    let mut config = KubixConfig::load();
+    
+    // Check if alias already exists
+    if let Some(existing_context) = config.aliases.get(alias) {
+        display::print_warning(&format!("Alias '{}' already exists: '{}'", alias, existing_context));
+        if !utils::prompt_for_confirmation("Do you want to overwrite it?") {
+            display::print_error("Operation cancelled.");
+            return;
+        }
+    }
+    
+    // Add or update the alias
+    config.aliases.insert(alias.to_string(), context.clone());
+    
+    match config.save() {
+        Ok(_) => {
+            display::print_success(&format!("Alias '{}' added for context '{}'", alias, context));
+        }
+        Err(err) => {
+            display::print_error_and_exit(&format!("Failed to save config: {}", err));
+        }
+    }
+}
+
+/// Remove a context alias
+pub fn remove_alias(alias: &str) {
+    let mut config = KubixConfig::load();
+    
+    if config.aliases.remove(alias).is_some() {
+        match config.save() {
+            Ok(_) => {
+                display::print_success(&format!("Alias '{}' removed successfully", alias));
+            }
+            Err(err) => {
+                display::print_error_and_exit(&format!("Failed to save config: {}", err));
+            }
+        }
+    } else {
+        display::print_error(&format!("Alias '{}' not found", alias));
+    }
+}
+
+/// Mark contexts matching a pattern as protected
+pub fn add_protected(pattern: &str) {
+    let mut config = KubixConfig::load();
+    
+    if config.protected_contexts.iter().any(|existing| existing == pattern) {
+        display::print_warning(&format!("Protected context pattern '{}' already exists", pattern));
+        return;
+    }
+    
+    config.protected_contexts.push(pattern.to_string());
+    
+    match config.save() {
+        Ok(_) => {
+            display::print_success(&format!("Contexts matching '{}' are now protected", pattern));
+        }
+        Err(err) => {
+            display::print_error_and_exit(&format!("Failed to save config: {}", err));
+        }
+    }
+}
+
+/// Remove a protected context pattern
+pub fn remove_protected(pattern: &str) {
+    let mut config = KubixConfig::load();
+    let before = config.protected_contexts.len();
+    config.protected_contexts.retain(|existing| existing != pattern);
+    
+    if config.protected_contexts.len() < before {
+        match config.save() {
+            Ok(_) => {
+                display::print_success(&format!("Protected context pattern '{}' removed successfully", pattern));
+            }
+            Err(err) => {
+                display::print_error_and_exit(&format!("Failed to save config: {}", err));
+            }
+        }
+    } else {
+        display::print_error(&format!("Protected context pattern '{}' not found", pattern));
+    }
+}
+
 /// Default commands for the configuration
 fn default_commands() -> HashMap<String, String> {
     let mut commands = HashMap::new();
@@ -308,6 +525,21 @@ fn default_interpreters() -> HashMap<String, String> {
     interpreters
 }
 
+/// Default log highlight rules for the configuration
+fn default_highlights() -> HashMap<String, String> {
+    HashMap::new()
+}
+
+/// Default context aliases for the configuration
+fn default_aliases() -> HashMap<String, String> {
+    HashMap::new()
+}
+
+/// Default protected context patterns for the configuration
+fn default_protected_contexts() -> Vec<String> {
+    Vec::new()
+}
+
 /// Default settings for the configuration
 fn default_settings() -> Settings {
     Settings::default()
@@ -316,4 +548,9 @@ fn default_settings() -> Settings {
 /// Default script delay in seconds
 fn default_script_delay() -> u64 {
     10
+}
+
+/// Default cache TTL in seconds
+fn default_cache_ttl() -> u64 {
+    300
 } 
\ No newline at end of file
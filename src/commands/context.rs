@@ -1,30 +1,74 @@
-use crate::{utils, kubectl, display};
+use crate::{utils, kubectl, display, session, audit, cache};
+use crate::cli::CtxCommands;
+use crate::commands::context_edit;
+use crate::audit::AuditEntry;
+use crate::history::History;
+use crate::kubeconfig::KubeConfig;
+use crate::commands::config::KubixConfig;
+use crate::commands::protection;
 
-/// Handle the ctx command - list contexts or switch to one by pattern
-pub fn handle_ctx_command(name_pattern: Option<&str>) {
+/// Handle the ctx command - list contexts, show history, or switch to one by pattern ("-" for the previous one)
+/// With `shell`, the switch only affects the calling shell and prints exports to evaluate
+pub fn handle_ctx_command(name_pattern: Option<&str>, show_history: bool, shell: bool, exit_shell: bool) {
+    if exit_shell {
+        session::print_exit_exports();
+        return;
+    }
+    
+    if show_history {
+        list_context_history();
+        return;
+    }
+    
+    if shell {
+        // stdout is evaluated by the shell, so keep everything else on stderr
+        display::redirect_output_to_stderr();
+    }
+    
     match name_pattern {
         None => list_contexts_with_current(),
-        Some(pattern) => switch_to_context_by_pattern(pattern),
+        Some("-") => switch_to_previous_context(shell),
+        Some(pattern) => switch_to_context_by_pattern(pattern, shell),
     }
 }
 
-/// Resolve a context pattern to an exact context name
+/// Handle the kubeconfig management subcommands of ctx
+pub fn handle_ctx_subcommand(command: &CtxCommands) {
+    match command {
+        CtxCommands::Import { file, overwrite } => context_edit::import_kubeconfig(file, *overwrite),
+        CtxCommands::Rename { context, new_name } => context_edit::rename_context(context, new_name),
+        CtxCommands::Delete { context } => context_edit::delete_context(context),
+        CtxCommands::Export { context, output } => context_edit::export_context(context, output.as_deref()),
+    }
+}
+
+/// Resolve a context pattern (or alias) to an exact context name
 /// Returns None if user cancels, exits process if no matches found
 pub fn resolve_context_pattern(pattern: &str) -> Option<String> {
+    let config = KubixConfig::load();
+    if let Some(context) = config.resolve_alias(pattern) {
+        display::print_working(&format!("Using context: {} (alias {})", context, pattern));
+        return Some(context);
+    }
+    
     display::print_working(&format!("Resolving context with pattern {}...", pattern));
-    // Get all contexts
-    let contexts = match get_all_contexts() {
-        Ok(contexts) => contexts,
+    // Find matching contexts, by name or by one of their aliases
+    let matches = cache::matching_names(&cache::contexts_key(), get_all_contexts, |context| {
+        context.contains(pattern)
+            || config.aliases_for(context).iter().any(|alias| alias.contains(pattern))
+    });
+    let mut matches = match matches {
+        Ok(matches) => matches,
         Err(error) => {
             display::print_error_and_exit(&format!("Error getting contexts: {}", error));
         }
     };
     
-    // Find matching contexts
-    let matches: Vec<String> = contexts
-        .into_iter()
-        .filter(|context| context.contains(pattern))
-        .collect();
+    // Rank recently used contexts first
+    let recent = History::load().recent_contexts();
+    matches.sort_by_key(|context| {
+        recent.iter().position(|entry| &entry.name == context).unwrap_or(usize::MAX)
+    });
     
     let resolved_context = utils::select_from_matches(matches, pattern, "context");
     if let Some(context) = &resolved_context {
@@ -37,10 +81,15 @@ pub fn resolve_context_pattern(pattern: &str) -> Option<String> {
 pub fn list_contexts_with_current() {
     let current_context = get_current_context();
     
+    if let Some(session_file) = session::active_session() {
+        display::print_info(&format!("Session-scoped context active for this shell ({})", session_file.display()));
+    }
+    
     display::print_working("Listing contexts...");
-    match kubectl::execute_kubectl(&["config", "get-contexts", "-o", "name"]) {
-        Ok(output) => {
-            display::print_contexts_table(&output, current_context.as_deref());
+    match KubeConfig::load() {
+        Ok(kubeconfig) => {
+            let config = KubixConfig::load();
+            display::print_contexts_table(&kubeconfig, current_context.as_deref(), &config);
         }
         Err(error) => {
             display::print_error_and_exit(&format!("Error listing contexts: {}", error));
@@ -49,19 +98,82 @@ pub fn list_contexts_with_current() {
 }
 
 /// Switch to a context by pattern (with fuzzy matching and interactive selection)
-pub fn switch_to_context_by_pattern(pattern: &str) {
+pub fn switch_to_context_by_pattern(pattern: &str, shell: bool) {
     if let Some(resolved_context) = resolve_context_pattern(pattern) {
-        use_context(&resolved_context);
+        switch_context(&resolved_context, shell);
     } else {
         display::print_error("Operation cancelled.");
     }
 }
 
+/// Switch back to the previously used context (like `cd -`)
+pub fn switch_to_previous_context(shell: bool) {
+    let current_context = get_current_context();
+    match History::load().previous_context(current_context.as_deref()) {
+        Some(previous) => switch_context(&previous, shell),
+        None => display::print_error_and_exit("No previous context in history"),
+    }
+}
+
+/// Switch either the current kubeconfig or just the calling shell to a context
+fn switch_context(name: &str, shell: bool) {
+    if shell {
+        use_context_in_shell(name);
+    } else {
+        use_context(name);
+    }
+}
+
+/// Switch only the calling shell to a context via a per-shell session kubeconfig
+pub fn use_context_in_shell(name: &str) {
+    let previous_context = get_current_context();
+    match session::enter_session(name) {
+        Ok(session_file) => {
+            display::print_success(&format!("Switched this shell session to context: {}", name));
+            protection::warn_if_protected(Some(name));
+            record_context_switch(previous_context.as_deref(), name);
+            audit::record(AuditEntry::new("ctx --shell", Some(name), None).exit_code(Some(0)));
+            session::print_session_exports(&session_file);
+        }
+        Err(error) => {
+            display::print_error_and_exit(&format!("Error switching context: {}", error));
+        }
+    }
+}
+
+/// Remember a context switch so `kubix ctx -` can jump back
+fn record_context_switch(previous_context: Option<&str>, name: &str) {
+    let mut history = History::load();
+    history.record_context(previous_context, name);
+    if let Err(err) = history.save() {
+        display::print_warning(&err);
+    }
+}
+
+/// List recently used contexts with when they were last switched to
+pub fn list_context_history() {
+    let recent = History::load().recent_contexts();
+    let current_context = get_current_context();
+    display::print_context_history_table(&recent, current_context.as_deref());
+}
+
 /// Switch to a specific kubectl context
+/// Inside a shell session only the session kubeconfig is changed, never the shared one
 pub fn use_context(name: &str) {    
-    match kubectl::execute_kubectl(&["config", "use-context", name]) {
-        Ok(_) => {
-            display::print_success(&format!("Successfully switched to context: {}", name));
+    let previous_context = get_current_context();
+    let result = match session::active_session() {
+        Some(session_file) => session::set_session_context(&session_file, name)
+            .map(|_| format!("Successfully switched to context: {} (this shell session only)", name)),
+        None => kubectl::execute_kubectl(&["config", "use-context", name])
+            .map(|_| format!("Successfully switched to context: {}", name)),
+    };
+    
+    match result {
+        Ok(message) => {
+            display::print_success(&message);
+            protection::warn_if_protected(Some(name));
+            record_context_switch(previous_context.as_deref(), name);
+            audit::record(AuditEntry::new("ctx", Some(name), None).exit_code(Some(0)));
         }
         Err(error) => {
             display::print_error_and_exit(&format!("Error switching context: {}", error));
@@ -77,8 +189,33 @@ pub fn get_current_context() -> Option<String> {
     }
 }
 
+/// Resolve a pattern to every matching context (for fan-out commands)
+/// An exact alias resolves to its context; globs like "prod-*" must match the whole name
+/// Exits if no context matches
+pub fn resolve_contexts(pattern: &str) -> Vec<String> {
+    if let Some(context) = KubixConfig::load().resolve_alias(pattern) {
+        return vec![context];
+    }
+    
+    let matches = cache::matching_names(&cache::contexts_key(), get_all_contexts, |context| {
+        utils::matches_pattern(pattern, context)
+    });
+    let matches = match matches {
+        Ok(matches) => matches,
+        Err(error) => {
+            display::print_error_and_exit(&format!("Error getting contexts: {}", error));
+        }
+    };
+    
+    if matches.is_empty() {
+        display::print_error_and_exit(&format!("No context found matching pattern: '{}'", pattern));
+    }
+    display::print_working(&format!("Using {} context(s): {}", matches.len(), matches.join(", ")));
+    matches
+}
+
 /// Get all available contexts as a vector
-fn get_all_contexts() -> Result<Vec<String>, String> {
+pub fn get_all_contexts() -> Result<Vec<String>, String> {
     match kubectl::execute_kubectl(&["config", "get-contexts", "-o", "name"]) {
         Ok(output) => {
             let contexts: Vec<String> = output
@@ -0,0 +1,390 @@
+use crate::{kubectl, display, utils, session, audit};
+use crate::audit::AuditEntry;
+use crate::commands::{protection, resolve_context_pattern};
+use crate::commands::config::KubixConfig;
+use crate::history::History;
+use crate::kubeconfig::KubeConfig;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Kubeconfig sections that are merged by name
+const SECTIONS: [(&str, &str); 3] = [("contexts", "context"), ("clusters", "cluster"), ("users", "user")];
+
+/// Result of comparing an imported kubeconfig section against the existing one
+#[derive(Default)]
+struct SectionDiff {
+    new: Vec<String>,
+    identical: Vec<String>,
+    conflicting: Vec<String>,
+}
+
+/// Merge contexts, clusters and users from another kubeconfig file into the shared kubeconfig
+/// Entries that exist with different content are conflicts and need `overwrite`.
+/// Replaced entries stay in the file that defines them, new ones go to the first kubeconfig file.
+pub fn import_kubeconfig(file: &str, overwrite: bool) {
+    let import_path = PathBuf::from(file);
+    if !import_path.is_file() {
+        display::print_error_and_exit(&format!("Kubeconfig file not found: {}", file));
+    }
+    
+    let base = kubeconfig_files();
+    let target_names = base.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ");
+    
+    display::print_working(&format!("Comparing {} with {}...", file, target_names));
+    let incoming = load_raw(std::slice::from_ref(&import_path))
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error reading {}: {}", file, error)));
+    let existing = load_raw(&base)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error reading kubeconfig: {}", error)));
+    
+    let diffs: Vec<(&str, SectionDiff)> = SECTIONS
+        .iter()
+        .map(|(section, _)| (*section, diff_section(&existing, &incoming, section)))
+        .collect();
+    
+    for (section, diff) in &diffs {
+        if !diff.new.is_empty() {
+            display::print_info(&format!("New {}: {}", section, diff.new.join(", ")));
+        }
+        if !diff.identical.is_empty() {
+            display::print_info(&format!("Already present (identical) {}: {}", section, diff.identical.join(", ")));
+        }
+        if !diff.conflicting.is_empty() {
+            display::print_warning(&format!("Conflicting {} (same name, different content): {}", section, diff.conflicting.join(", ")));
+        }
+    }
+    
+    let has_new = diffs.iter().any(|(_, diff)| !diff.new.is_empty());
+    let conflicts: Vec<&String> = diffs.iter().flat_map(|(_, diff)| &diff.conflicting).collect();
+    
+    if !conflicts.is_empty() && !overwrite {
+        display::print_error_and_exit("Import aborted because of conflicts. Rename the entries in the file, or use --overwrite to replace them.");
+    }
+    if !has_new && (conflicts.is_empty() || !overwrite) {
+        display::print_success("Nothing to import - all entries are already present");
+        return;
+    }
+    
+    // Replacing existing contexts needs the same care as any other change to them
+    let conflicting_contexts = &diffs[0].1.conflicting;
+    for context in conflicting_contexts {
+        protection::confirm_mutating_action_or_exit(&AuditEntry::new("ctx import --overwrite", Some(context), None).command(file));
+    }
+    
+    let plan = plan_import(&base, &diffs, overwrite)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error reading kubeconfig: {}", error)));
+    for (path, entries) in &plan {
+        display::print_info(&format!("{} will receive: {}", path.display(), entries.join(", ")));
+    }
+    if !utils::prompt_for_confirmation(&format!("Merge {} into {}?", file, target_names)) {
+        display::print_error("Operation cancelled.");
+        return;
+    }
+    
+    let mut result = Ok(());
+    for (path, entries) in &plan {
+        result = merge_into(path, &incoming, entries);
+        if result.is_err() {
+            break;
+        }
+        display::print_success(&format!("Updated {} (backup: {})", path.display(), backup_path(path).display()));
+    }
+    
+    audit::record(AuditEntry::new("ctx import", None, None).command(file).exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+    match result {
+        Ok(_) => display::print_success(&format!("Imported {}", file)),
+        Err(error) => display::print_error_and_exit(&format!("Error importing kubeconfig: {}", error)),
+    }
+}
+
+/// Rename a context (given by pattern or alias), keeping aliases and history in sync
+pub fn rename_context(pattern: &str, new_name: &str) {
+    let Some(old_name) = resolve_context_pattern(pattern) else {
+        display::print_error("Operation cancelled.");
+        return;
+    };
+    
+    let base = kubeconfig_files();
+    if let Ok(kubeconfig) = KubeConfig::load_from(&base)
+        && kubeconfig.context(new_name).is_some() {
+        display::print_error_and_exit(&format!("Context '{}' already exists", new_name));
+    }
+    
+    let entry = AuditEntry::new("ctx rename", Some(&old_name), None).command(new_name);
+    protection::confirm_mutating_action_or_exit(&entry);
+    
+    let result = kubectl::execute_with_kubeconfig(&["config", "rename-context", &old_name, new_name], &base);
+    audit::record(entry.exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+    if let Err(error) = result {
+        display::print_error_and_exit(&format!("Error renaming context: {}", error));
+    }
+    
+    let mut config = KubixConfig::load();
+    let renamed_aliases = config.aliases_for(&old_name);
+    if !renamed_aliases.is_empty() {
+        for alias in &renamed_aliases {
+            config.aliases.insert(alias.clone(), new_name.to_string());
+        }
+        if let Err(err) = config.save() {
+            display::print_warning(&err);
+        }
+    }
+    
+    let mut history = History::load();
+    history.rename_context(&old_name, new_name);
+    if let Err(err) = history.save() {
+        display::print_warning(&err);
+    }
+    
+    display::print_success(&format!("Renamed context '{}' to '{}'", old_name, new_name));
+    if config.is_protected_context(&old_name) && !config.is_protected_context(new_name) {
+        display::print_warning(&format!("'{}' no longer matches a protected context pattern - use `kubix config add-protected` to protect it again", new_name));
+    }
+}
+
+/// Delete a context (given by pattern or alias) and prune its cluster and user if nothing else uses them
+pub fn delete_context(pattern: &str) {
+    let Some(name) = resolve_context_pattern(pattern) else {
+        display::print_error("Operation cancelled.");
+        return;
+    };
+    
+    let base = kubeconfig_files();
+    let kubeconfig = KubeConfig::load_from(&base)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error reading kubeconfig: {}", error)));
+    let Some(context) = kubeconfig.context(&name) else {
+        display::print_error_and_exit(&format!("Context '{}' not found in kubeconfig", name));
+    };
+    let (cluster, user) = (context.cluster.clone(), context.user.clone());
+    
+    let entry = AuditEntry::new("ctx delete", Some(&name), None);
+    if protection::is_protected(&name) {
+        protection::confirm_mutating_action_or_exit(&entry);
+    } else if !utils::prompt_for_confirmation(&format!("Delete context '{}'?", name)) {
+        display::print_error("Operation cancelled.");
+        return;
+    }
+    
+    let result = kubectl::execute_with_kubeconfig(&["config", "delete-context", &name], &base);
+    audit::record(entry.exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+    if let Err(error) = result {
+        display::print_error_and_exit(&format!("Error deleting context: {}", error));
+    }
+    display::print_success(&format!("Deleted context '{}'", name));
+    
+    prune_orphans(&base, &cluster, &user);
+    
+    let mut config = KubixConfig::load();
+    let aliases = config.aliases_for(&name);
+    if !aliases.is_empty() {
+        config.aliases.retain(|_, target| target != &name);
+        match config.save() {
+            Ok(_) => display::print_info(&format!("Removed aliases: {}", aliases.join(", "))),
+            Err(err) => display::print_warning(&err),
+        }
+    }
+    
+    let mut history = History::load();
+    history.forget_context(&name);
+    if let Err(err) = history.save() {
+        display::print_warning(&err);
+    }
+    
+    if kubeconfig.current_context == name {
+        display::print_warning("The deleted context was the current context - switch with `kubix ctx <name>`");
+    }
+}
+
+/// Write a minimal standalone kubeconfig (with embedded credentials) for one context
+pub fn export_context(pattern: &str, output: Option<&str>) {
+    if output.is_none() {
+        // stdout carries the kubeconfig, so keep everything else on stderr
+        display::redirect_output_to_stderr();
+    }
+    
+    let Some(name) = resolve_context_pattern(pattern) else {
+        display::print_error("Operation cancelled.");
+        return;
+    };
+    
+    let result = kubectl::execute_kubectl(&["config", "view", "--minify", "--flatten", "--context", &name]);
+    let kubeconfig = match result {
+        Ok(kubeconfig) => kubeconfig,
+        Err(error) => display::print_error_and_exit(&format!("Error exporting context: {}", error)),
+    };
+    
+    let written = match output {
+        Some(path) => write_kubeconfig(Path::new(path), &kubeconfig, false)
+            .map(|_| display::print_success(&format!("Exported context '{}' to {}", name, path))),
+        None => {
+            display::print_stdout_line(kubeconfig.trim_end());
+            Ok(())
+        }
+    };
+    
+    audit::record(AuditEntry::new("ctx export", Some(&name), None)
+        .command(output.unwrap_or("-"))
+        .exit_code(Some(if written.is_ok() { 0 } else { 1 })));
+    if let Err(error) = written {
+        display::print_error_and_exit(&format!("Error exporting context: {}", error));
+    }
+}
+
+/// Remove a deleted context's cluster and user when no other context refers to them
+fn prune_orphans(base: &[PathBuf], cluster: &str, user: &str) {
+    let Ok(kubeconfig) = KubeConfig::load_from(base) else {
+        return;
+    };
+    
+    if kubeconfig.orphaned_clusters().iter().any(|orphan| orphan == cluster) {
+        match kubectl::execute_with_kubeconfig(&["config", "delete-cluster", cluster], base) {
+            Ok(_) => display::print_info(&format!("Pruned unused cluster '{}'", cluster)),
+            Err(error) => display::print_warning(&format!("Could not delete cluster '{}': {}", cluster, error.trim())),
+        }
+    }
+    if kubeconfig.orphaned_users().iter().any(|orphan| orphan == user) {
+        match kubectl::execute_with_kubeconfig(&["config", "delete-user", user], base) {
+            Ok(_) => display::print_info(&format!("Pruned unused user '{}'", user)),
+            Err(error) => display::print_warning(&format!("Could not delete user '{}': {}", user, error.trim())),
+        }
+    }
+}
+
+/// Load kubeconfig files as raw JSON (credentials included, for exact comparison)
+fn load_raw(paths: &[PathBuf]) -> Result<Value, String> {
+    let output = kubectl::execute_with_kubeconfig(&["config", "view", "--raw", "-o", "json"], paths)?;
+    serde_json::from_str(&output).map_err(|e| format!("Failed to parse kubeconfig: {}", e))
+}
+
+/// Named entries of a kubeconfig section (e.g. "contexts") mapped to their content
+fn named_entries(config: &Value, section: &str) -> BTreeMap<String, Value> {
+    let entry_key = SECTIONS
+        .iter()
+        .find(|(name, _)| *name == section)
+        .map(|(_, key)| *key)
+        .unwrap_or_default();
+    
+    config[section]
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| Some((entry["name"].as_str()?.to_string(), entry[entry_key].clone())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Compare one section of an imported kubeconfig against the existing one
+fn diff_section(existing: &Value, incoming: &Value, section: &str) -> SectionDiff {
+    let existing = named_entries(existing, section);
+    let mut diff = SectionDiff::default();
+    
+    for (name, content) in named_entries(incoming, section) {
+        match existing.get(&name) {
+            None => diff.new.push(name),
+            Some(current) if *current == content => diff.identical.push(name),
+            Some(_) => diff.conflicting.push(name),
+        }
+    }
+    diff
+}
+
+/// Decide which kubeconfig file receives which imported entries ("section/name")
+/// A replaced entry goes to the first file defining it (the one kubectl reads it from), new entries to the first file
+fn plan_import(
+    base: &[PathBuf],
+    diffs: &[(&str, SectionDiff)],
+    overwrite: bool,
+) -> Result<Vec<(PathBuf, Vec<String>)>, String> {
+    let files = base
+        .iter()
+        .map(|path| load_raw(std::slice::from_ref(path)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut plan: Vec<(PathBuf, Vec<String>)> = base.iter().map(|path| (path.clone(), Vec::new())).collect();
+    
+    for (section, diff) in diffs {
+        for name in &diff.new {
+            plan[0].1.push(format!("{}/{}", section, name));
+        }
+        if !overwrite {
+            continue;
+        }
+        for name in &diff.conflicting {
+            let owner = files
+                .iter()
+                .position(|config| named_entries(config, section).contains_key(name))
+                .unwrap_or(0);
+            plan[owner].1.push(format!("{}/{}", section, name));
+        }
+    }
+    
+    plan.retain(|(_, entries)| !entries.is_empty());
+    Ok(plan)
+}
+
+/// Merge the given imported entries ("section/name") into one kubeconfig file, replacing its own versions
+fn merge_into(path: &Path, incoming: &Value, entries: &[String]) -> Result<(), String> {
+    // Stage just these entries in a kubeconfig of their own, without a current-context,
+    // so merging it first replaces them while the file keeps everything else
+    let mut staged = serde_json::json!({ "apiVersion": "v1", "kind": "Config" });
+    for (section, _) in SECTIONS {
+        let selected: Vec<Value> = incoming[section]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|entry| {
+                entry["name"]
+                    .as_str()
+                    .is_some_and(|name| entries.contains(&format!("{}/{}", section, name)))
+            })
+            .cloned()
+            .collect();
+        staged[section] = Value::Array(selected);
+    }
+    
+    let staged_path = path.with_extension("kubix-import");
+    write_kubeconfig(&staged_path, &staged.to_string(), false)?;
+    let merged = kubectl::execute_with_kubeconfig(&["config", "view", "--raw"], &[staged_path.clone(), path.to_path_buf()]);
+    let _ = fs::remove_file(&staged_path);
+    write_kubeconfig(path, &merged?, true)
+}
+
+/// The kubeconfig files kubix edits, with symlinks resolved so rewriting one keeps the link intact
+fn kubeconfig_files() -> Vec<PathBuf> {
+    session::base_kubeconfig_paths()
+        .into_iter()
+        .map(|path| fs::canonicalize(&path).unwrap_or(path))
+        .collect()
+}
+
+/// Where the previous version of a kubeconfig is kept before kubix rewrites it
+fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".kubix-backup");
+    PathBuf::from(backup)
+}
+
+/// Write a kubeconfig readable only by the owner, optionally backing up the existing file
+fn write_kubeconfig(path: &Path, content: &str, backup: bool) -> Result<(), String> {
+    // Replace the file a symlink points to rather than the symlink itself
+    let path = &fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    if backup && path.exists() {
+        fs::copy(path, backup_path(path)).map_err(|e| format!("Failed to back up {}: {}", path.display(), e))?;
+    }
+    
+    // Write next to the target and rename, so a failure never leaves a half-written kubeconfig
+    let temp_path = path.with_extension("kubix-tmp");
+    fs::write(&temp_path, content).map_err(|e| format!("Failed to write {}: {}", temp_path.display(), e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| format!("Failed to set permissions on {}: {}", temp_path.display(), e))?;
+    }
+    fs::rename(&temp_path, path).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
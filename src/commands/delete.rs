@@ -0,0 +1,46 @@
+use crate::{kubectl, display, utils, audit};
+use crate::audit::AuditEntry;
+use crate::commands::{pods, protection, resolve_context_pattern, resolve_namespace_pattern};
+
+/// Handle the delete command - delete one or more pods picked by pattern
+pub fn handle_delete_command(pod_pattern: &str, context_pattern: Option<&str>, namespace_pattern: Option<&str>, dry_run: bool) {
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    let (context, namespace) = (resolved_context.as_deref(), resolved_namespace.as_deref());
+    protection::warn_if_protected(context);
+
+    // Never from the cache - the list must be exactly what gets deleted
+    let matching_pods = pods::find_pods_fresh(pod_pattern, context, namespace);
+    let Some(selected) = utils::select_multiple_from_matches(matching_pods, pod_pattern, "pod") else {
+        display::print_error_and_exit("Operation cancelled.");
+    };
+
+    let resources: Vec<String> = selected.iter().map(|pod| format!("pod/{}", pod)).collect();
+    let location = protection::describe_target(context, namespace);
+    display::print_affected_resources(&format!("🗑️  {} pod(s) will be deleted{}:", selected.len(), location), &resources);
+    if dry_run {
+        display::print_info("Dry run - nothing was deleted.");
+        return;
+    }
+
+    let entry = AuditEntry::new("delete", context, namespace).pod(&selected.join(","));
+    protection::confirm_mutating_action_or_exit(&entry);
+    if !utils::prompt_for_confirmation(&format!("Delete {} pod(s)?", selected.len())) {
+        display::print_error_and_exit("Operation cancelled.");
+    }
+
+    let mut args = vec!["delete"];
+    args.extend(resources.iter().map(String::as_str));
+    let result = kubectl::execute_with_context(&args, context, namespace);
+    pods::forget_cached_pods(context, namespace);
+    audit::record(entry.exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+
+    match result {
+        Ok(output) => {
+            for line in output.lines().filter(|line| !line.trim().is_empty()) {
+                display::print_success(line.trim());
+            }
+        }
+        Err(error) => display::print_error_and_exit(&format!("Error deleting pods: {}", error.trim())),
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{display, k8s, time};
+use crate::commands::{events, pods, protection, resolve_context_pattern, resolve_namespace_pattern};
+use crate::commands::pods::PodSelectors;
+use crate::display::{ConditionDisplay, ContainerDetailDisplay, PropertyDisplay, VolumeDisplay};
+
+/// How many of the pod's most recent events are shown
+const RECENT_EVENTS: usize = 10;
+
+/// Handle the describe command - show a condensed summary of one pod
+pub fn handle_describe_command(
+    pod_pattern: &str,
+    selectors: &PodSelectors,
+    context_pattern: Option<&str>,
+    namespace_pattern: Option<&str>,
+    all_namespaces: bool,
+) {
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    protection::warn_if_protected(resolved_context.as_deref());
+
+    let context = resolved_context.as_deref();
+    let select = || {
+        pods::select_pod_with_namespace(pod_pattern, selectors, context, resolved_namespace.as_deref(), all_namespaces)
+            .unwrap_or_else(|| display::print_error_and_exit(&format!("No pod found matching: {}", selectors.describe(Some(pod_pattern)))))
+    };
+    let describe = |pod_name: &str, namespace: Option<&str>| {
+        display::print_working(&format!("Describing pod {}...", pod_name));
+        pods::get_pod(pod_name, context, namespace)
+    };
+
+    let (mut pod_name, mut pod_namespace) = select();
+    let mut result = describe(&pod_name, pod_namespace.as_deref());
+
+    // The pod may have been picked from stale cached names - pick again and retry once
+    if result.is_err() && pods::is_gone(&pod_name, context, pod_namespace.as_deref()) {
+        (pod_name, pod_namespace) = select();
+        result = describe(&pod_name, pod_namespace.as_deref());
+    }
+    let namespace = pod_namespace.as_deref();
+    let pod = result.unwrap_or_else(|error| display::print_error_and_exit(&format!("Error getting pod {}: {}", pod_name, error.trim())));
+    let events = match events::get_pod_events(&pod_name, context, namespace) {
+        Ok(events) => events,
+        Err(error) => {
+            display::print_warning(&format!("Could not load events: {}", error.trim()));
+            Vec::new()
+        }
+    };
+
+    let title = format!("🔎 Pod {}", pod.metadata.name);
+    display::print_properties_table(&title, &pod_properties(&pod, context));
+    display::print_conditions_table(&pod.status.conditions.iter().map(condition_row).collect::<Vec<_>>());
+    display::print_container_details_table(&pod.spec.containers.iter().map(|container| container_row(&pod, container)).collect::<Vec<_>>());
+    display::print_volumes_table(&pod.spec.volumes.iter().map(|volume| volume_row(&pod, volume)).collect::<Vec<_>>());
+
+    let recent = &events[events.len().saturating_sub(RECENT_EVENTS)..];
+    let header = format!("📰 Events (last {} of {}):", recent.len(), events.len());
+    let rows: Vec<_> = recent.iter().map(|event| events::event_row(event, event.occurrences(), false)).collect();
+    display::print_events_table(&header, &rows, false);
+}
+
+/// Overview rows: where the pod runs and how it is doing
+fn pod_properties(pod: &k8s::Pod, context: Option<&str>) -> Vec<PropertyDisplay> {
+    let (ready, total) = pod.ready_containers();
+    let mut properties = vec![
+        ("Namespace", pod.metadata.namespace.clone().unwrap_or_else(|| "-".to_string())),
+        ("Status", pod.display_status()),
+        ("Phase", pod.status.phase.clone().unwrap_or_else(|| "Unknown".to_string())),
+        ("Ready", format!("{}/{}", ready, total)),
+        ("Restarts", pod.restart_count().to_string()),
+        ("Node", pod.spec.node_name.clone().unwrap_or_else(|| "<none>".to_string())),
+        ("IP", pod.status.pod_ip.clone().unwrap_or_else(|| "<none>".to_string())),
+        ("QoS", pod.status.qos_class.clone().unwrap_or_else(|| "-".to_string())),
+        ("Owner", pod.owner().unwrap_or_else(|| "-".to_string())),
+        ("Created", pod.metadata.creation_timestamp.as_deref().map(time::format_ago).unwrap_or_else(|| "-".to_string())),
+    ];
+    if let Some(context) = context {
+        properties.insert(0, ("Context", context.to_string()));
+    }
+    properties
+        .into_iter()
+        .map(|(property, value)| PropertyDisplay { property: property.to_string(), value })
+        .collect()
+}
+
+/// Table row of a pod condition
+fn condition_row(condition: &k8s::PodCondition) -> ConditionDisplay {
+    ConditionDisplay {
+        condition: condition.condition_type.clone(),
+        status: condition.status.clone(),
+        reason: condition.reason.clone().unwrap_or_else(|| "-".to_string()),
+        message: display::truncate(condition.message.as_deref().unwrap_or("-"), 80),
+    }
+}
+
+/// Table row of a container with its status, probes and last termination
+fn container_row(pod: &k8s::Pod, container: &k8s::Container) -> ContainerDetailDisplay {
+    let status = pod.status.container_statuses.iter().find(|status| status.name == container.name);
+    let probes: Vec<String> = [
+        ("liveness", &container.liveness_probe),
+        ("readiness", &container.readiness_probe),
+        ("startup", &container.startup_probe),
+    ]
+    .into_iter()
+    .filter_map(|(kind, probe)| probe.as_ref().map(|probe| format!("{}: {}", kind, describe_probe(probe))))
+    .collect();
+
+    ContainerDetailDisplay {
+        container: container.name.clone(),
+        image: container.image.clone(),
+        state: status.map(|status| container_state(&status.state)).unwrap_or_else(|| "Unknown".to_string()),
+        ready: if status.is_some_and(|status| status.ready) { "✓" } else { "✗" }.to_string(),
+        restarts: status.map(|status| status.restart_count).unwrap_or_default().to_string(),
+        last_termination: status
+            .and_then(|status| status.last_state.terminated.as_ref())
+            .map(describe_termination)
+            .unwrap_or_else(|| "-".to_string()),
+        probes: if probes.is_empty() { "-".to_string() } else { probes.join("\n") },
+    }
+}
+
+/// Table row of a volume with where each container mounts it
+fn volume_row(pod: &k8s::Pod, volume: &k8s::Volume) -> VolumeDisplay {
+    let mounts: Vec<String> = pod
+        .spec
+        .containers
+        .iter()
+        .flat_map(|container| {
+            container.volume_mounts.iter().filter(|mount| mount.name == volume.name).map(|mount| {
+                let read_only = if mount.read_only { " (ro)" } else { "" };
+                format!("{}:{}{}", container.name, mount.mount_path, read_only)
+            })
+        })
+        .collect();
+
+    VolumeDisplay {
+        volume: volume.name.clone(),
+        source: describe_volume_source(volume),
+        mounts: if mounts.is_empty() { "-".to_string() } else { mounts.join("\n") },
+    }
+}
+
+/// Current state of a container: Running, or the reason it is waiting or terminated
+fn container_state(state: &k8s::ContainerState) -> String {
+    if let Some(waiting) = &state.waiting {
+        return waiting.reason.clone().unwrap_or_else(|| "Waiting".to_string());
+    }
+    if let Some(terminated) = &state.terminated {
+        return terminated.reason.clone().unwrap_or_else(|| "Terminated".to_string());
+    }
+    if state.running.is_some() {
+        return "Running".to_string();
+    }
+    "Unknown".to_string()
+}
+
+/// Reason, exit code and time of a container's last termination (e.g. "OOMKilled (exit 137) 5m ago")
+fn describe_termination(terminated: &k8s::ContainerStateTerminated) -> String {
+    let when = terminated
+        .finished_at
+        .as_deref()
+        .map(|finished| format!(" {}", time::format_ago(finished)))
+        .unwrap_or_default();
+    format!(
+        "{} (exit {}){}",
+        terminated.reason.as_deref().unwrap_or("Terminated"),
+        terminated.exit_code,
+        when
+    )
+}
+
+/// What a probe checks and how often (e.g. "GET :8080/healthz every 10s")
+fn describe_probe(probe: &k8s::Probe) -> String {
+    let handler = if let Some(http) = &probe.http_get {
+        format!("GET :{}{}", port_name(&http.port), http.path.as_deref().unwrap_or("/"))
+    } else if let Some(tcp) = &probe.tcp_socket {
+        format!("tcp :{}", port_name(&tcp.port))
+    } else if let Some(grpc) = &probe.grpc {
+        format!("grpc :{}", port_name(&grpc.port))
+    } else if let Some(exec) = &probe.exec {
+        format!("exec {}", exec.command.join(" "))
+    } else {
+        "unknown".to_string()
+    };
+
+    let mut timing = format!("every {}s", probe.period_seconds.unwrap_or(10));
+    if probe.initial_delay_seconds > 0 {
+        timing.push_str(&format!(", delay {}s", probe.initial_delay_seconds));
+    }
+    format!("{} {}", handler, timing)
+}
+
+/// A probe port as written in the spec - a number or a named port
+fn port_name(port: &serde_json::Value) -> String {
+    match port {
+        serde_json::Value::String(name) => name.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Kind of a volume with the object backing it (e.g. "ConfigMap web-config")
+fn describe_volume_source(volume: &k8s::Volume) -> String {
+    let Some((kind, source)) = volume.source.iter().next() else {
+        return "-".to_string();
+    };
+    let field = |name: &str| source.get(name).and_then(|value| value.as_str()).unwrap_or("?").to_string();
+    match kind.as_str() {
+        "configMap" => format!("ConfigMap {}", field("name")),
+        "secret" => format!("Secret {}", field("secretName")),
+        "persistentVolumeClaim" => format!("PVC {}", field("claimName")),
+        "hostPath" => format!("HostPath {}", field("path")),
+        "emptyDir" => "EmptyDir".to_string(),
+        "projected" => "Projected".to_string(),
+        "downwardAPI" => "DownwardAPI".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{kubectl, display, utils};
+use crate::commands::config::KubixConfig;
+use crate::commands::namespace::DEFAULT_NAMESPACE;
+use crate::kubeconfig::KubeConfig;
+use std::env;
+use std::path::Path;
+use std::thread;
+use std::time::Instant;
+
+/// How long to wait for an API server before calling it unreachable
+const REQUEST_TIMEOUT: &str = "--request-timeout=5s";
+
+/// Permissions kubix needs for its everyday commands: (label, verb, resource)
+const RBAC_CHECKS: [(&str, &str, &str); 4] = [
+    ("get pods", "get", "pods"),
+    ("list pods", "list", "pods"),
+    ("exec into pods", "create", "pods/exec"),
+    ("read logs", "get", "pods/log"),
+];
+
+/// Outcome of a single health check
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    Skip,
+}
+
+/// A single health check result row
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub scope: String,
+    pub check: String,
+    pub status: CheckStatus,
+    pub details: String,
+}
+
+impl CheckResult {
+    fn new(scope: &str, check: &str, status: CheckStatus, details: impl Into<String>) -> Self {
+        Self {
+            scope: scope.to_string(),
+            check: check.to_string(),
+            status,
+            details: details.into(),
+        }
+    }
+}
+
+/// Handle the doctor command - check kubectl, the kubeconfig and cluster access
+/// Without a pattern only the current context is checked
+pub fn handle_doctor_command(context_pattern: Option<&str>) {
+    display::print_working("Checking kubectl and kubeconfig...");
+    let mut results = vec![check_kubectl()];
+    if results[0].status == CheckStatus::Fail {
+        display::print_doctor_table(&results);
+        std::process::exit(1);
+    }
+    
+    let kubeconfig = match KubeConfig::load() {
+        Ok(kubeconfig) => {
+            results.push(CheckResult::new("local", "kubeconfig", CheckStatus::Pass, format!(
+                "{} contexts, {} clusters, {} users",
+                kubeconfig.contexts.len(), kubeconfig.clusters.len(), kubeconfig.users.len()
+            )));
+            kubeconfig
+        }
+        Err(error) => {
+            results.push(CheckResult::new("local", "kubeconfig", CheckStatus::Fail, first_line(&error)));
+            display::print_doctor_table(&results);
+            std::process::exit(1);
+        }
+    };
+    
+    let contexts = select_contexts(&kubeconfig, context_pattern);
+    if contexts.is_empty() {
+        let message = match context_pattern {
+            Some(pattern) => format!("No contexts matching '{}'", pattern),
+            None => "No current context set".to_string(),
+        };
+        results.push(CheckResult::new("local", "contexts", CheckStatus::Fail, message));
+    }
+    
+    display::print_working(&format!("Checking {} context(s)...", contexts.len()));
+    let context_results: Vec<Vec<CheckResult>> = thread::scope(|scope| {
+        let handles: Vec<_> = contexts
+            .iter()
+            .map(|context| {
+                let kubeconfig = &kubeconfig;
+                scope.spawn(move || check_context(kubeconfig, context))
+            })
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap_or_default()).collect()
+    });
+    results.extend(context_results.into_iter().flatten());
+    
+    display::print_doctor_table(&results);
+    if results.iter().any(|result| result.status == CheckStatus::Fail) {
+        std::process::exit(1);
+    }
+}
+
+/// Contexts to check: those matching the pattern (or alias), or the current context
+fn select_contexts(kubeconfig: &KubeConfig, pattern: Option<&str>) -> Vec<String> {
+    let Some(pattern) = pattern else {
+        return if kubeconfig.current_context.is_empty() {
+            Vec::new()
+        } else {
+            vec![kubeconfig.current_context.clone()]
+        };
+    };
+    
+    if let Some(context) = KubixConfig::load().resolve_alias(pattern) {
+        return vec![context];
+    }
+    kubeconfig
+        .contexts
+        .iter()
+        .map(|context| context.name.clone())
+        .filter(|name| utils::matches_pattern(pattern, name))
+        .collect()
+}
+
+/// kubectl must be on PATH - report its client version
+fn check_kubectl() -> CheckResult {
+    match kubectl::execute_kubectl(&["version", "--client", "-o", "json"]) {
+        Ok(output) => {
+            let version = serde_json::from_str::<serde_json::Value>(&output)
+                .ok()
+                .and_then(|value| value["clientVersion"]["gitVersion"].as_str().map(str::to_string))
+                .unwrap_or_else(|| "unknown version".to_string());
+            CheckResult::new("local", "kubectl", CheckStatus::Pass, format!("client {}", version))
+        }
+        Err(error) => CheckResult::new("local", "kubectl", CheckStatus::Fail, format!("kubectl not usable: {}", first_line(&error))),
+    }
+}
+
+/// Run all checks against one context
+fn check_context(kubeconfig: &KubeConfig, context: &str) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+    let Some(entry) = kubeconfig.context(context) else {
+        results.push(CheckResult::new(context, "context", CheckStatus::Fail, "not found in kubeconfig"));
+        return results;
+    };
+    
+    // References and credential plugins can be checked without talking to the cluster
+    match kubeconfig.server(&entry.cluster) {
+        Some(server) => results.push(CheckResult::new(context, "cluster", CheckStatus::Pass, format!("{} ({})", entry.cluster, server))),
+        None => results.push(CheckResult::new(context, "cluster", CheckStatus::Fail, format!("cluster '{}' is not defined", entry.cluster))),
+    }
+    match kubeconfig.user(&entry.user) {
+        Some(user) => {
+            if let Some(exec) = &user.exec {
+                if command_exists(&exec.command) {
+                    results.push(CheckResult::new(context, "exec plugin", CheckStatus::Pass, format!("{} found", exec.command)));
+                } else {
+                    results.push(CheckResult::new(context, "exec plugin", CheckStatus::Fail, format!("{} not found on PATH", exec.command)));
+                }
+            }
+        }
+        None if entry.user.is_empty() => {}
+        None => results.push(CheckResult::new(context, "user", CheckStatus::Fail, format!("user '{}' is not defined", entry.user))),
+    }
+    
+    // API server reachability, with timing
+    let started = Instant::now();
+    let reachable = kubectl::execute_kubectl(&["--context", context, REQUEST_TIMEOUT, "get", "--raw", "/version"]);
+    let elapsed = format!("{}ms", started.elapsed().as_millis());
+    match &reachable {
+        Ok(output) => {
+            let version = serde_json::from_str::<serde_json::Value>(output)
+                .ok()
+                .and_then(|value| value["gitVersion"].as_str().map(str::to_string))
+                .unwrap_or_else(|| "unknown version".to_string());
+            results.push(CheckResult::new(context, "API server", CheckStatus::Pass, format!("reachable in {} (server {})", elapsed, version)));
+        }
+        Err(error) => {
+            let (check, details) = classify_error(error);
+            results.push(CheckResult::new(context, check, CheckStatus::Fail, format!("{} after {}", details, elapsed)));
+            results.push(CheckResult::new(context, "RBAC", CheckStatus::Skip, "cluster not reachable"));
+            return results;
+        }
+    }
+    
+    // Credentials and RBAC - can-i needs a working login, so it doubles as the credentials check
+    let namespace = entry.namespace.clone().filter(|ns| !ns.is_empty()).unwrap_or_else(|| DEFAULT_NAMESPACE.to_string());
+    let mut credentials = CheckResult::new(context, "credentials", CheckStatus::Pass, "authenticated");
+    let mut rbac = Vec::new();
+    for (label, verb, resource) in RBAC_CHECKS {
+        let check = format!("can {} ({})", label, namespace);
+        if credentials.status == CheckStatus::Fail {
+            rbac.push(CheckResult::new(context, &check, CheckStatus::Skip, "credentials not working"));
+            continue;
+        }
+        match kubectl::execute_kubectl(&["--context", context, REQUEST_TIMEOUT, "auth", "can-i", verb, resource, "-n", &namespace]) {
+            Ok(_) => rbac.push(CheckResult::new(context, &check, CheckStatus::Pass, "yes")),
+            // A plain "no" exits non-zero without an error message
+            Err(error) if error.trim().is_empty() => rbac.push(CheckResult::new(context, &check, CheckStatus::Warn, "no")),
+            Err(error) => {
+                let (check_name, details) = classify_error(&error);
+                credentials = CheckResult::new(context, check_name, CheckStatus::Fail, details);
+                rbac.push(CheckResult::new(context, &check, CheckStatus::Skip, "credentials not working"));
+            }
+        }
+    }
+    results.push(credentials);
+    results.extend(rbac);
+    
+    results
+}
+
+/// Turn kubectl stderr into a check name and a short explanation
+fn classify_error(error: &str) -> (&'static str, String) {
+    let lower = error.to_lowercase();
+    let details = first_line(error);
+    if lower.contains("exec plugin") || lower.contains("getting credentials") || lower.contains("executable file not found") {
+        ("exec plugin", details)
+    } else if lower.contains("unauthorized") || lower.contains("you must be logged in") || lower.contains("token") && lower.contains("expired") {
+        ("credentials", details)
+    } else if lower.contains("certificate") || lower.contains("x509") {
+        ("TLS", details)
+    } else {
+        ("API server", details)
+    }
+}
+
+/// Whether a command is runnable: an existing path, or found on PATH
+fn command_exists(command: &str) -> bool {
+    if command.contains(std::path::MAIN_SEPARATOR) {
+        return Path::new(command).is_file();
+    }
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| dir.join(command).is_file()))
+        .unwrap_or(false)
+}
+
+/// First non-empty line of an error message
+fn first_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("unknown error")
+        .to_string()
+}
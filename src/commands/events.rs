@@ -0,0 +1,304 @@
+use crate::{kubectl, display, k8s, time, utils};
+use crate::commands::{protection, resolve_context_pattern, resolve_namespace_pattern};
+use crate::display::EventDisplay;
+use owo_colors::OwoColorize;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+/// Warning events younger than this mark a pod in the pods listing
+const RECENT_WARNING_SECONDS: i64 = 3600;
+
+/// Delay before reopening a watch stream the API server closed
+const RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// Repeats of the same event about the same object, folded into one row
+struct EventGroup<'a> {
+    event: &'a k8s::Event,
+    count: u32,
+    last_seen: Option<(i64, u32)>,
+}
+
+/// Handle the events command - list events, warnings first, or follow new ones
+pub fn handle_events_command(
+    pattern: Option<&str>,
+    context_pattern: Option<&str>,
+    namespace_pattern: Option<&str>,
+    all_namespaces: bool,
+    watch: bool,
+) {
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    protection::warn_if_protected(resolved_context.as_deref());
+    let (context, namespace) = (resolved_context.as_deref(), resolved_namespace.as_deref());
+
+    list_events(pattern, context, namespace, all_namespaces);
+    if watch {
+        watch_events(pattern, context, namespace, all_namespaces);
+    }
+}
+
+/// List events about objects matching the pattern, warnings first and repeats grouped
+fn list_events(pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>, all_namespaces: bool) {
+    match pattern {
+        None => display::print_working("Listing events..."),
+        Some(p) => display::print_working(&format!("Listing events for objects matching '{}'...", p)),
+    }
+    let events = match get_events(context, namespace, None, all_namespaces) {
+        Ok(events) => events,
+        Err(error) => display::print_error_and_exit(&format!("Error listing events: {}", error.trim())),
+    };
+
+    let matching: Vec<&k8s::Event> = events
+        .iter()
+        .filter(|event| pattern.is_none_or(|p| utils::matches_pattern(p, &event.involved_object.name)))
+        .collect();
+    let groups = group_events(&matching);
+    let rows: Vec<EventDisplay> = groups
+        .iter()
+        .map(|group| event_row(group.event, group.count, all_namespaces))
+        .collect();
+
+    let warnings = groups.iter().filter(|group| group.event.is_warning()).count();
+    let header = match pattern {
+        Some(p) => format!("📰 Events for '{}' ({} warning(s)):", p, warnings),
+        None => format!("📰 Events ({} warning(s)):", warnings),
+    };
+    display::print_events_table(&header, &rows, true);
+}
+
+/// Get events of a namespace (or of every namespace), oldest first
+pub fn get_events(
+    context: Option<&str>,
+    namespace: Option<&str>,
+    field_selector: Option<&str>,
+    all_namespaces: bool,
+) -> Result<Vec<k8s::Event>, String> {
+    let mut base_args = vec!["get", "events", "-o", "json"];
+    if let Some(field_selector) = field_selector {
+        base_args.extend(["--field-selector", field_selector]);
+    }
+    if all_namespaces {
+        base_args.push("--all-namespaces");
+    }
+    let output = kubectl::execute_with_context(&base_args, context, namespace)?;
+    let mut events = k8s::parse::<k8s::EventList>(&output, "events")?.items;
+    events.sort_by_key(|event| event.last_seen().and_then(time::parse_rfc3339));
+    Ok(events)
+}
+
+/// Events about a single pod, oldest first
+pub fn get_pod_events(pod_name: &str, context: Option<&str>, namespace: Option<&str>) -> Result<Vec<k8s::Event>, String> {
+    let field_selector = format!("involvedObject.kind=Pod,involvedObject.name={}", pod_name);
+    get_events(context, namespace, Some(&field_selector), false)
+}
+
+/// Pods with warning events in the last hour, as "namespace/name"
+/// Events are only a hint in listings, so failing to load them just means no markers
+pub fn pods_with_recent_warnings(context: Option<&str>, namespace: Option<&str>, all_namespaces: bool) -> HashSet<String> {
+    let Ok(events) = get_events(context, namespace, Some("type=Warning,involvedObject.kind=Pod"), all_namespaces) else {
+        return HashSet::new();
+    };
+    let now = time::now_seconds();
+    events
+        .iter()
+        .filter(|event| {
+            event.is_warning() && event
+                .last_seen()
+                .and_then(time::parse_rfc3339)
+                .is_some_and(|(seconds, _)| now - seconds <= RECENT_WARNING_SECONDS)
+        })
+        .map(|event| event.object_key())
+        .collect()
+}
+
+/// Table row of an event that occurred `count` times
+pub fn event_row(event: &k8s::Event, count: u32, with_namespace: bool) -> EventDisplay {
+    let object = format!("{}/{}", event.involved_object.kind, event.involved_object.name);
+    let object = match (&event.involved_object.namespace, with_namespace) {
+        (Some(namespace), true) => format!("{} ({})", object, namespace),
+        _ => object,
+    };
+    EventDisplay {
+        last_seen: event.last_seen().map(time::format_ago).unwrap_or_else(|| "-".to_string()),
+        event_type: event.event_type.clone(),
+        reason: event.reason.clone(),
+        object,
+        count: count.to_string(),
+        message: display::truncate(event.message.trim(), 100),
+    }
+}
+
+/// Fold repeats of the same event about the same object into one group
+/// Warnings come first, then the most recently seen
+fn group_events<'a>(events: &[&'a k8s::Event]) -> Vec<EventGroup<'a>> {
+    let mut groups: Vec<EventGroup> = Vec::new();
+    let mut index: HashMap<(String, &str, &str, &str, &str), usize> = HashMap::new();
+
+    for &event in events {
+        let key = (
+            event.object_key(),
+            event.involved_object.kind.as_str(),
+            event.event_type.as_str(),
+            event.reason.as_str(),
+            event.message.trim(),
+        );
+        let last_seen = event.last_seen().and_then(time::parse_rfc3339);
+        match index.get(&key) {
+            Some(&i) => {
+                let group = &mut groups[i];
+                group.count += event.occurrences();
+                if last_seen >= group.last_seen {
+                    group.event = event;
+                    group.last_seen = last_seen;
+                }
+            }
+            None => {
+                index.insert(key, groups.len());
+                groups.push(EventGroup { event, count: event.occurrences(), last_seen });
+            }
+        }
+    }
+
+    groups.sort_by(|a, b| {
+        b.event.is_warning()
+            .cmp(&a.event.is_warning())
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+    });
+    groups
+}
+
+/// Print new events as they happen until interrupted, reopening the stream when the API server closes it
+/// Events that happen while reconnecting are listed before the stream resumes
+fn watch_events(pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>, all_namespaces: bool) {
+    display::print_info("Watching for new events (Ctrl+C to stop)...");
+    let mut received_events = false;
+    let mut last_seen = Some((time::now_seconds(), 0));
+    loop {
+        match stream_events(pattern, context, namespace, all_namespaces, &mut received_events, &mut last_seen) {
+            Err(error) if !received_events => {
+                display::print_error_and_exit(&format!("Error watching events: {}", error.trim()));
+            }
+            Err(error) => display::print_warning(&format!("Event stream ended: {}", error.trim())),
+            Ok(()) => {}
+        }
+        thread::sleep(RECONNECT_DELAY);
+        print_missed_events(pattern, context, namespace, all_namespaces, &mut last_seen);
+    }
+}
+
+/// Print the events that happened after `last_seen` - a new `--watch-only` stream would not show them
+fn print_missed_events(
+    pattern: Option<&str>,
+    context: Option<&str>,
+    namespace: Option<&str>,
+    all_namespaces: bool,
+    last_seen: &mut Option<(i64, u32)>,
+) {
+    let events = match get_events(context, namespace, None, all_namespaces) {
+        Ok(events) => events,
+        Err(error) => {
+            display::print_warning(&format!("Event stream reconnected - events since it ended may be missing: {}", error.trim()));
+            return;
+        }
+    };
+
+    let since = *last_seen;
+    let missed: Vec<&k8s::Event> = events
+        .iter()
+        .filter(|event| event.last_seen().and_then(time::parse_rfc3339) > since)
+        .collect();
+    if let Some(newest) = missed.iter().filter_map(|event| event.last_seen().and_then(time::parse_rfc3339)).max() {
+        *last_seen = Some(newest);
+    }
+
+    let shown: Vec<&k8s::Event> = missed.into_iter().filter(|event| is_watched(event, pattern)).collect();
+    display::print_info(&format!("Event stream reconnected - {} event(s) while it was down", shown.len()));
+    for event in shown {
+        print_event_line(event, all_namespaces);
+    }
+}
+
+/// Whether a streamed event is about an object matching the pattern
+fn is_watched(event: &k8s::Event, pattern: Option<&str>) -> bool {
+    !event.reason.is_empty() && pattern.is_none_or(|p| utils::matches_pattern(p, &event.involved_object.name))
+}
+
+/// Follow one `kubectl get events --watch-only` stream, printing matching events
+/// `last_seen` keeps the time of the newest event received
+fn stream_events(
+    pattern: Option<&str>,
+    context: Option<&str>,
+    namespace: Option<&str>,
+    all_namespaces: bool,
+    received_events: &mut bool,
+    last_seen: &mut Option<(i64, u32)>,
+) -> Result<(), String> {
+    let mut base_args = vec!["get", "events", "--watch-only", "--output-watch-events", "-o", "json"];
+    if all_namespaces {
+        base_args.push("--all-namespaces");
+    }
+    let kubectl_args = kubectl::build_args(&base_args, context, namespace);
+
+    let mut child = Command::new("kubectl")
+        .args(&kubectl_args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| "Failed to start kubectl process".to_string())?;
+
+    // Drain stderr concurrently so kubectl never blocks on a full stderr pipe during a long watch
+    let stderr = child.stderr.take();
+    let stderr_reader = thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = stderr {
+            let _ = pipe.read_to_string(&mut output);
+        }
+        output
+    });
+
+    if let Some(stdout) = child.stdout.take() {
+        // Events are concatenated JSON documents
+        let events = serde_json::Deserializer::from_reader(BufReader::new(stdout))
+            .into_iter::<k8s::WatchEvent<k8s::Event>>();
+        for event in events {
+            let Ok(event) = event else { break };
+            *received_events = true;
+            let event = event.object;
+            *last_seen = (*last_seen).max(event.last_seen().and_then(time::parse_rfc3339));
+            if is_watched(&event, pattern) {
+                print_event_line(&event, all_namespaces);
+            }
+        }
+    }
+
+    let status = child.wait();
+    let stderr = stderr_reader.join().unwrap_or_default();
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        Ok(_) => Err(stderr),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Print a streamed event as a single line
+fn print_event_line(event: &k8s::Event, with_namespace: bool) {
+    let row = event_row(event, event.occurrences(), with_namespace);
+    let event_type = if event.is_warning() {
+        row.event_type.yellow().bold().to_string()
+    } else {
+        row.event_type.bright_black().to_string()
+    };
+    let count = if event.occurrences() > 1 { format!(" (x{})", row.count) } else { String::new() };
+    display::print_line(&format!(
+        "{} {} {} {}{} {}",
+        event.last_seen().unwrap_or("-").bright_black(),
+        event_type,
+        row.reason.bold(),
+        row.object.bright_blue(),
+        count,
+        row.message
+    ));
+}
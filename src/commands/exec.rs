@@ -1,79 +1,209 @@
-use crate::{kubectl, display};
-use crate::commands::{pods, config, resolve_context_pattern, resolve_namespace_pattern};
+use crate::{kubectl, display, audit};
+use crate::audit::AuditEntry;
+use crate::commands::{pods, config, context, protection, resolve_context_pattern, resolve_namespace_pattern};
+use crate::commands::pods::PodSelectors;
+use crate::display::FanoutDisplay;
 use std::fs;
 use std::io::Write;
 use std::process::{Command, Stdio};
 use std::path::Path;
+use std::thread;
+
+/// Outcome of running a command in one context of a fan-out
+struct FanoutResult {
+    context: String,
+    pod: Option<String>,
+    exit_code: Option<i32>,
+    output: String,
+}
 
 /// Handle the unified exec command
+#[allow(clippy::too_many_arguments)]
 pub fn handle_exec_command(
     pod_pattern: &str, 
+    selectors: &PodSelectors,
     command: Option<&str>,
     script: Option<&str>, 
     context_pattern: Option<&str>, 
-    namespace_pattern: Option<&str>
+    contexts_pattern: Option<&str>,
+    namespace_pattern: Option<&str>,
+    all_namespaces: bool,
 ) {
+    if let (Some(contexts_pattern), Some(cmd)) = (contexts_pattern, command) {
+        let contexts = context::resolve_contexts(contexts_pattern);
+        let resolved_command = config::KubixConfig::load().resolve_command(cmd);
+        run_command_across_contexts(pod_pattern, selectors, &resolved_command, &contexts, namespace_pattern);
+        return;
+    }
+    
     // Resolve context and namespace patterns
-    let resolved_context = context_pattern.and_then(|pattern| resolve_context_pattern(pattern));
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
     let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    protection::warn_if_protected(resolved_context.as_deref());
+    
+    if command.is_some() && script.is_some() {
+        // This should be prevented by clap's argument group, but handle it gracefully
+        display::print_error_and_exit("Cannot specify both command and script. Use either --command or --script, not both.");
+    }
+    
+    // Find the pod - in all-namespaces mode its own namespace is used from here on
+    let context = resolved_context.as_deref();
+    let select = || {
+        pods::select_pod_with_namespace(pod_pattern, selectors, context, resolved_namespace.as_deref(), all_namespaces)
+            .unwrap_or_else(|| display::print_error_and_exit(&format!("No pod found matching: {}", selectors.describe(Some(pod_pattern)))))
+    };
     
     // Load configuration for command/script resolution
     let config = config::KubixConfig::load();
     
-    match (command, script) {
-        (Some(cmd), None) => {
-            // Execute a command
-            let resolved_command = config.resolve_command(cmd);
-            run_command_on_pod(pod_pattern, &resolved_command, resolved_context.as_deref(), resolved_namespace.as_deref());
-        }
-        (None, Some(script_input)) => {
-            // Execute a script
-            let resolved_script = config.resolve_script(script_input);
-            exec_script_on_pod(pod_pattern, &resolved_script, &config, resolved_context.as_deref(), resolved_namespace.as_deref());
-        }
-        (None, None) => {
-            // Default to bash
-            bash_to_pod(pod_pattern, resolved_context.as_deref(), resolved_namespace.as_deref());
-        }
-        (Some(_), Some(_)) => {
-            // This should be prevented by clap's argument group, but handle it gracefully
-            display::print_error_and_exit("Cannot specify both command and script. Use either --command or --script, not both.");
-        }
+    let run = |pod_name: &str, namespace: Option<&str>| match (command, script) {
+        // Execute a command
+        (Some(cmd), _) => run_command_on_pod(pod_name, &config.resolve_command(cmd), context, namespace),
+        // Execute a script
+        (None, Some(script_input)) => exec_script_on_pod(pod_name, &config.resolve_script(script_input), &config, context, namespace),
+        // Default to bash
+        (None, None) => bash_to_pod(pod_name, context, namespace),
+    };
+    
+    let (pod_name, pod_namespace) = select();
+    let mut result = run(&pod_name, pod_namespace.as_deref());
+    
+    // The pod may have been picked from stale cached names - pick again and retry once
+    if result.is_err() && pods::is_gone(&pod_name, context, pod_namespace.as_deref()) {
+        let (pod_name, pod_namespace) = select();
+        result = run(&pod_name, pod_namespace.as_deref());
+    }
+    if let Err(error) = result {
+        display::print_error_and_exit(&error);
     }
 }
 
 /// Open a bash shell session to a pod
-pub fn bash_to_pod(pod_pattern: &str, context: Option<&str>, namespace: Option<&str>) {
-    if let Some(pod_name) = pods::select_pod(pod_pattern, context, namespace) {
-        display::print_working(&format!("Opening bash session to pod: {}", pod_name));
-        
-        let base_args = vec!["exec", "-it", &pod_name, "--", "bash"];
-        
-        if !kubectl::execute_interactive_with_context(&base_args, context, namespace) {
-            display::print_error_and_exit("Failed to open bash session");
-        }
-    } else {
-        display::print_error_and_exit(&format!("No pod found matching pattern: {}", pod_pattern));
+pub fn bash_to_pod(pod_name: &str, context: Option<&str>, namespace: Option<&str>) -> Result<(), String> {
+    let entry = AuditEntry::new("exec", context, namespace).pod(pod_name).command("bash");
+    protection::confirm_mutating_action_or_exit(&entry);
+    display::print_working(&format!("Opening bash session to pod: {}", pod_name));
+    
+    let base_args = vec!["exec", "-it", pod_name, "--", "bash"];
+    
+    let exit_code = kubectl::execute_interactive_with_context(&base_args, context, namespace);
+    audit::record(entry.exit_code(exit_code));
+    if exit_code != Some(0) {
+        return Err("Failed to open bash session".to_string());
     }
+    Ok(())
 }
 
 /// Run a command on a pod
 pub fn run_command_on_pod(
-    pod_pattern: &str, 
+    pod_name: &str, 
     command: &str, 
     context: Option<&str>, 
     namespace: Option<&str>
-) {
-    if let Some(pod_name) = pods::select_pod(pod_pattern, context, namespace) {
-        display::print_working(&format!("Running command '{}' on pod: {}", command, pod_name));
-        
-        let base_args = vec!["exec", "-it", &pod_name, "--", "sh", "-c", command];
+) -> Result<(), String> {
+    let entry = AuditEntry::new("exec", context, namespace).pod(pod_name).command(command);
+    protection::confirm_mutating_action_or_exit(&entry);
+    display::print_working(&format!("Running command '{}' on pod: {}", command, pod_name));
+    
+    let base_args = vec!["exec", "-it", pod_name, "--", "sh", "-c", command];
+    
+    let exit_code = kubectl::execute_interactive_with_context(&base_args, context, namespace);
+    audit::record(entry.exit_code(exit_code));
+    if exit_code != Some(0) {
+        return Err("Failed to run command".to_string());
+    }
+    Ok(())
+}
+
+/// Run a command in the newest matching pod of every context, in parallel
+/// Output is grouped per context, followed by a summary table
+pub fn run_command_across_contexts(pod_pattern: &str, selectors: &PodSelectors, command: &str, contexts: &[String], namespace: Option<&str>) {
+    // Confirm protected contexts up front, so the parallel run never waits on a prompt
+    for context in contexts {
+        protection::confirm_mutating_action_or_exit(
+            &AuditEntry::new("exec", Some(context), namespace).command(command)
+        );
+    }
+    
+    display::print_working(&format!("Running '{}' on pods matching {} in {} context(s)...", command, selectors.describe(Some(pod_pattern)), contexts.len()));
+    let results: Vec<FanoutResult> = thread::scope(|scope| {
+        let handles: Vec<_> = contexts
+            .iter()
+            .map(|context| scope.spawn(move || run_command_in_context(pod_pattern, selectors, command, context, namespace)))
+            .collect();
+        contexts
+            .iter()
+            .zip(handles)
+            .map(|(context, handle)| handle.join().unwrap_or_else(|_| FanoutResult {
+                context: context.clone(),
+                pod: None,
+                exit_code: None,
+                output: "command panicked".to_string(),
+            }))
+            .collect()
+    });
+    
+    for result in &results {
+        let detail = match (&result.pod, result.exit_code) {
+            (Some(pod), Some(code)) => format!("{} (exit {})", pod, code),
+            (Some(pod), None) => format!("{} (no exit code)", pod),
+            (None, _) => "no matching pod".to_string(),
+        };
+        display::print_context_section(&result.context, &detail);
+        for line in result.output.lines() {
+            display::print_line(line);
+        }
         
-        if !kubectl::execute_interactive_with_context(&base_args, context, namespace) {
-            display::print_error_and_exit("Failed to run command");
+        if let Some(pod) = &result.pod {
+            audit::record(AuditEntry::new("exec", Some(&result.context), namespace)
+                .pod(pod)
+                .command(command)
+                .exit_code(result.exit_code));
         }
-    } else {
-        display::print_error_and_exit(&format!("No pod found matching pattern: {}", pod_pattern));
+    }
+    display::print_line("");
+    
+    let rows: Vec<FanoutDisplay> = results
+        .iter()
+        .map(|result| FanoutDisplay {
+            context: result.context.clone(),
+            pod: result.pod.clone().unwrap_or_else(|| "-".to_string()),
+            exit: result.exit_code.map(|code| code.to_string()).unwrap_or_else(|| "-".to_string()),
+            output: result.output.lines().find(|line| !line.trim().is_empty()).unwrap_or_default().to_string(),
+        })
+        .collect();
+    display::print_fanout_table(&rows);
+    
+    if results.iter().any(|result| result.exit_code != Some(0)) {
+        std::process::exit(1);
+    }
+}
+
+/// Run a command non-interactively in the newest pod matching a pattern in one context
+fn run_command_in_context(pod_pattern: &str, selectors: &PodSelectors, command: &str, context: &str, namespace: Option<&str>) -> FanoutResult {
+    let Some(pod_name) = pods::find_newest_pod(Some(pod_pattern), selectors, Some(context), namespace, None) else {
+        return FanoutResult {
+            context: context.to_string(),
+            pod: None,
+            exit_code: None,
+            output: format!("No pod found matching: {}", selectors.describe(Some(pod_pattern))),
+        };
+    };
+    
+    let base_args = ["exec", &pod_name, "--", "sh", "-c", command];
+    let (exit_code, output) = match kubectl::execute_output_with_context(&base_args, Some(context), namespace) {
+        Ok(output) => {
+            let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            (output.status.code(), text)
+        }
+        Err(error) => (None, error),
+    };
+    FanoutResult {
+        context: context.to_string(),
+        pod: Some(pod_name),
+        exit_code,
+        output,
     }
 }
 
@@ -107,76 +237,75 @@ fn get_interpreter_for_script(script_path: &str, config: &config::KubixConfig) -
 
 /// Execute a local script on a pod
 pub fn exec_script_on_pod(
-    pod_pattern: &str, 
+    pod_name: &str, 
     script_path: &str,
     config: &config::KubixConfig,
     context: Option<&str>, 
     namespace: Option<&str>
-) {
-    if let Some(pod_name) = pods::select_pod(pod_pattern, context, namespace) {
-        display::print_working(&format!("Executing script '{}' on pod: {}", script_path, pod_name));
-
-        // Read the script content
-        let script_content = fs::read_to_string(script_path)
-            .unwrap_or_else(|_| {
-                display::print_error_and_exit(&format!("Failed to read script file: {}", script_path));
-            });
-        
-        // Determine the interpreter to use
-        let interpreter = get_interpreter_for_script(script_path, &config);
-        
-        // Build kubectl command
-        let mut cmd = Command::new("kubectl");
-        
-        if let Some(ctx) = context {
-            cmd.args(&["--context", ctx]);
-        }
-        
-        if let Some(ns) = namespace {
-            cmd.args(&["-n", ns]);
+) -> Result<(), String> {
+    // Read the script content
+    let script_content = fs::read_to_string(script_path)
+        .unwrap_or_else(|_| {
+            display::print_error_and_exit(&format!("Failed to read script file: {}", script_path));
+        });
+    
+    let entry = AuditEntry::new("script", context, namespace).pod(pod_name).script(script_path, &script_content);
+    protection::confirm_mutating_action_or_exit(&entry);
+    display::print_working(&format!("Executing script '{}' on pod: {}", script_path, pod_name));
+    
+    // Determine the interpreter to use
+    let interpreter = get_interpreter_for_script(script_path, config);
+    
+    // Build kubectl command
+    let mut cmd = Command::new("kubectl");
+    
+    if let Some(ctx) = context {
+        cmd.args(["--context", ctx]);
+    }
+    
+    if let Some(ns) = namespace {
+        cmd.args(["-n", ns]);
+    }
+    
+    // Choose execution strategy based on interpreter detection
+    match interpreter {
+        Some(interp) => {
+            // Use detected interpreter directly
+            display::print_info(&format!("🔍 Detected interpreter: {}", interp));
+            
+            // Standard interpreter execution - all interpreters can read from stdin
+            cmd.args(["exec", "-i", pod_name, "--", &interp]);
         }
-        
-        // Choose execution strategy based on interpreter detection
-        match interpreter {
-            Some(interp) => {
-                // Use detected interpreter directly
-                display::print_info(&format!("🔍 Detected interpreter: {}", interp));
-                
-                // Standard interpreter execution - all interpreters can read from stdin
-                cmd.args(&["exec", "-i", &pod_name, "--", &interp]);
-            }
-            None => {
-                // Fall back to shell execution
-                display::print_info("🔍 No file extension detected, using shell with shebang detection");
-                cmd.args(&["exec", "-i", &pod_name, "--", "sh"]);
-            }
+        None => {
+            // Fall back to shell execution
+            display::print_info("🔍 No file extension detected, using shell with shebang detection");
+            cmd.args(["exec", "-i", pod_name, "--", "sh"]);
         }
-        
-        cmd.stdin(Stdio::piped());
-        let mut child = cmd.spawn()
-            .expect("Failed to spawn kubectl process");
+    }
+    
+    cmd.stdin(Stdio::piped());
+    let mut child = cmd.spawn()
+        .expect("Failed to spawn kubectl process");
 
-        // Wait before executing script if configured
-        if config.settings.script_delay_seconds > 0 {
-            display::print_info(&format!("Waiting {} seconds for pod to be ready...", config.settings.script_delay_seconds));
-            std::thread::sleep(std::time::Duration::from_secs(config.settings.script_delay_seconds));
-        }
+    // Wait before executing script if configured
+    if config.settings.script_delay_seconds > 0 {
+        display::print_info(&format!("Waiting {} seconds for pod to be ready...", config.settings.script_delay_seconds));
+        std::thread::sleep(std::time::Duration::from_secs(config.settings.script_delay_seconds));
+    }
 
-        // Send script content to the process
-        if let Some(stdin) = child.stdin.as_mut() {
-            stdin.write_all(script_content.as_bytes())
-                .expect("Failed to write to stdin");
-        }
-        
-        let status = child.wait()
-            .expect("Failed to wait for kubectl process");
-        
-        if !status.success() {
-            display::print_error_and_exit("Failed to execute script");
-        } else {
-            display::print_success("Script executed successfully");
-        }
-    } else {
-        display::print_error_and_exit(&format!("No pod found matching pattern: {}", pod_pattern));
+    // Send script content to the process
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin.write_all(script_content.as_bytes())
+            .expect("Failed to write to stdin");
+    }
+    
+    let status = child.wait()
+        .expect("Failed to wait for kubectl process");
+    audit::record(entry.exit_code(status.code()));
+    
+    if !status.success() {
+        return Err("Failed to execute script".to_string());
     }
+    display::print_success("Script executed successfully");
+    Ok(())
 }
@@ -1,104 +1,322 @@
-use crate::{kubectl, display};
-use crate::commands::{pods, resolve_context_pattern, resolve_namespace_pattern};
-use owo_colors::OwoColorize;
+use crate::{kubectl, display, time, utils, k8s};
+use crate::display::{LogLevelDisplay, LogMessageDisplay, LogStatDisplay};
+use crate::commands::pods::PodSelectors;
+use crate::commands::{pods, config, workloads, protection, resolve_context_pattern, resolve_namespace_pattern};
+use owo_colors::{DynColors, OwoColorize};
+use std::collections::HashMap;
 use std::process::{Command, Stdio};
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Read};
+use std::thread;
+use std::time::{Duration, Instant};
 use regex::Regex;
 
-/// Handle the logs command - view logs from a pod
+/// How long follow mode keeps reconnecting without new output before giving up
+/// Generous enough for a replacement pod to pull its image and start
+const GIVE_UP_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// Delay between reconnect attempts in follow mode
+const RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// Level keywords recognised when computing log statistics
+const LEVEL_PATTERN: &str = r"(?i)\b(trace|debug|info|notice|warn|warning|error|err|fatal|critical|crit|panic)\b";
+
+/// How the logs command presents its output
+#[derive(Clone, Copy, PartialEq)]
+enum LogsMode {
+    Static,
+    Follow,
+    Stats,
+}
+
+/// Where to read logs from and which kubectl logs options to apply
+struct LogsSource<'a> {
+    context: Option<&'a str>,
+    namespace: Option<&'a str>,
+    container: Option<&'a str>,
+    tail: Option<u32>,
+    previous: bool,
+    timestamps: bool,
+    /// Prefix each line with its pod name (when showing several pods)
+    prefix_pods: bool,
+}
+
+impl LogsSource<'_> {
+    /// Build kubectl logs args for a pod, resuming from a timestamp if one is given
+    fn build_args(&self, pod_name: &str, follow: bool, since_time: Option<&str>) -> Vec<String> {
+        let mut args = vec!["logs".to_string(), pod_name.to_string()];
+
+        if follow {
+            args.push("-f".to_string());
+        }
+
+        // Timestamps let follow mode resume exactly where the stream stopped
+        if follow || self.timestamps {
+            args.push("--timestamps".to_string());
+        }
+
+        match since_time {
+            Some(since) => args.push(format!("--since-time={}", since)),
+            None => {
+                if let Some(tail_lines) = self.tail {
+                    args.push("--tail".to_string());
+                    args.push(tail_lines.to_string());
+                }
+            }
+        }
+
+        if self.previous {
+            args.push("-p".to_string());
+        }
+
+        if let Some(container_name) = self.container {
+            args.push("-c".to_string());
+            args.push(container_name.to_string());
+        }
+
+        args
+    }
+}
+
+/// Options controlling which logs are read and how they are shown
+pub struct LogsOptions<'a> {
+    pub follow: bool,
+    pub tail: Option<u32>,
+    pub previous: bool,
+    pub container: Option<&'a str>,
+    pub grep: Option<&'a str>,
+    pub exclude: Option<&'a str>,
+    pub stats: bool,
+    pub top: usize,
+    pub all_pods: bool,
+    pub all_namespaces: bool,
+}
+
+/// Pods resolved from the logs target, with the workload or selector they came from
+struct LogsTarget {
+    pods: Vec<String>,
+    workload: Option<String>,
+    selector: Option<String>,
+    field_selector: Option<String>,
+    pattern: Option<String>,
+}
+
+impl LogsTarget {
+    /// Selectors used to find replacement replicas of the target
+    fn selectors(&self) -> PodSelectors<'_> {
+        PodSelectors {
+            labels: self.selector.as_deref(),
+            fields: self.field_selector.as_deref(),
+        }
+    }
+}
+
+/// Handle the logs command - view logs from a pod, workload or label/field selector
 pub fn handle_logs_command(
-    pod_pattern: &str,
+    pod_pattern: Option<&str>,
+    selectors: &PodSelectors,
     context_pattern: Option<&str>,
     namespace_pattern: Option<&str>,
-    follow: bool,
-    tail: Option<u32>,
-    previous: bool,
-    container: Option<&str>,
-    grep_pattern: Option<&str>,
-    exclude_pattern: Option<&str>,
+    options: &LogsOptions,
 ) {
     // Resolve context and namespace patterns
-    let resolved_context = context_pattern.and_then(|pattern| resolve_context_pattern(pattern));
-    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let requested_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    protection::warn_if_protected(resolved_context.as_deref());
     
-    // Find the pod using pattern matching
-    if let Some(pod_name) = pods::select_pod(pod_pattern, resolved_context.as_deref(), resolved_namespace.as_deref()) {
-        
-        // Compile regex patterns if provided
-        let grep_regex = grep_pattern.and_then(|pattern| {
-            match Regex::new(pattern) {
-                Ok(regex) => Some(regex),
-                Err(e) => {
-                    display::print_error_and_exit(&format!("Invalid grep pattern '{}': {}", pattern, e));
-                }
-            }
-        });
-        
-        let exclude_regex = exclude_pattern.and_then(|pattern| {
-            match Regex::new(pattern) {
-                Ok(regex) => Some(regex),
-                Err(e) => {
-                    display::print_error_and_exit(&format!("Invalid exclude pattern '{}': {}", pattern, e));
+    // Find the pod(s) using pattern, workload or selector matching - in all-namespaces mode the pod's namespace is used from here on
+    let resolve = || if options.all_namespaces {
+        resolve_logs_target_in_all_namespaces(pod_pattern, selectors, resolved_context.as_deref())
+    } else {
+        let target = resolve_logs_target(pod_pattern, selectors, options.all_pods, resolved_context.as_deref(), requested_namespace.as_deref());
+        (target, requested_namespace.clone())
+    };
+    let (mut target, mut resolved_namespace) = resolve();
+    
+    // Container states help explain crash loops, so fetch them for single-pod views.
+    // A single pod may have been picked from stale cached names - if it is gone, pick again once
+    let mut pod_info = single_pod_info(&target, resolved_context.as_deref(), resolved_namespace.as_deref());
+    if let (Err(_), [pod_name]) = (&pod_info, target.pods.as_slice())
+        && pods::is_gone(pod_name, resolved_context.as_deref(), resolved_namespace.as_deref()) {
+        (target, resolved_namespace) = resolve();
+        pod_info = single_pod_info(&target, resolved_context.as_deref(), resolved_namespace.as_deref());
+    }
+    let pod_info = pod_info.ok().flatten();
+    
+    // Compile filters and highlight rules
+    let filter = LogFilter::new(options.grep, options.exclude, &config::KubixConfig::load().highlights);
+    
+    let mode = if options.stats {
+        LogsMode::Stats
+    } else if options.follow {
+        LogsMode::Follow
+    } else {
+        LogsMode::Static
+    };
+    
+    // Show enhanced header with pod information
+    show_logs_header(&target, pod_info.as_ref(), options.container, resolved_context.as_deref(), resolved_namespace.as_deref(), mode, &filter);
+    
+    let source = LogsSource {
+        context: resolved_context.as_deref(),
+        namespace: resolved_namespace.as_deref(),
+        container: options.container,
+        tail: options.tail,
+        previous: options.previous,
+        timestamps: mode == LogsMode::Stats,
+        prefix_pods: target.pods.len() > 1,
+    };
+    
+    // Execute with filtering
+    match mode {
+        LogsMode::Follow => follow_logs(&target, &source, &filter),
+        LogsMode::Static => {
+            let result = execute_logs_with_filtering(&target.pods, &source, &filter);
+            
+            // A crash-looping container often has no output yet - the interesting logs are in the previous one
+            let has_previous = !source.previous && pod_info.as_ref().is_some_and(|pod| has_previous_container(pod, options.container));
+            match result {
+                Ok(0) | Err(_) if has_previous => {
+                    if let Err(error) = &result {
+                        display::print_warning(&format!("Failed to get logs: {}", error.trim()));
+                    }
+                    if utils::prompt_for_confirmation("Current container has no output yet. Show logs from the previous container?") {
+                        print_stream_marker("⏪ Logs from the previous container");
+                        let previous_source = LogsSource { previous: true, ..source };
+                        if let Err(error) = execute_logs_with_filtering(&target.pods, &previous_source, &filter) {
+                            display::print_error_and_exit(&format!("Failed to get logs: {}", error));
+                        }
+                    }
                 }
+                Err(error) => display::print_error_and_exit(&format!("Failed to get logs: {}", error)),
+                Ok(_) => {}
             }
-        });
-        
-        // Show enhanced header with pod information
-        show_logs_header(&pod_name, container, resolved_context.as_deref(), resolved_namespace.as_deref(), follow, grep_pattern, exclude_pattern);
-        
-        // Build kubectl logs command
-        let mut base_args = vec!["logs"];
-        base_args.push(&pod_name);
-        
-        // Convert options to owned strings to manage lifetimes
-        let mut additional_args = Vec::new();
-        
-        if follow {
-            additional_args.push("-f".to_string());
-        }
-        
-        if let Some(tail_lines) = tail {
-            additional_args.push("--tail".to_string());
-            additional_args.push(tail_lines.to_string());
-        }
-        
-        if previous {
-            additional_args.push("-p".to_string());
-        }
-        
-        if let Some(container_name) = container {
-            additional_args.push("-c".to_string());
-            additional_args.push(container_name.to_string());
         }
-        
-        // Combine base args with additional args
-        let mut all_args = base_args;
-        let additional_refs: Vec<&str> = additional_args.iter().map(|s| s.as_str()).collect();
-        all_args.extend(additional_refs);
-        
-        // Execute with filtering
-        if follow {
-            execute_logs_with_filtering_streaming(&all_args, resolved_context.as_deref(), resolved_namespace.as_deref(), grep_regex, exclude_regex);
-        } else {
-            execute_logs_with_filtering(&all_args, resolved_context.as_deref(), resolved_namespace.as_deref(), grep_regex, exclude_regex);
+        LogsMode::Stats => execute_logs_stats(&target.pods, &source, &filter, options.top),
+    }
+}
+
+/// Resolve the logs target (pod pattern, "kind/name" workload or label/field selector) to pods
+/// Exits if nothing matches; picks one representative pod unless all pods were requested
+fn resolve_logs_target(
+    pod_pattern: Option<&str>,
+    selectors: &PodSelectors,
+    all_pods: bool,
+    context: Option<&str>,
+    namespace: Option<&str>,
+) -> LogsTarget {
+    let workload_target = pod_pattern.and_then(workloads::parse_workload_target);
+    let description = selectors.describe(pod_pattern);
+    let selector = selectors.labels;
+    
+    let (workload, selector, pattern) = match workload_target {
+        Some((kind, name_pattern)) => {
+            let workload = workloads::select_workload(kind, name_pattern, context, namespace)
+                .unwrap_or_else(|| display::print_error_and_exit(&format!("No {} found matching pattern: {}", kind.resource(), name_pattern)));
+            let workload_selector = workloads::get_pod_selector(&workload, context, namespace)
+                .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error resolving pods of {}: {}", workload, error)));
+            
+            // An explicit -l narrows the workload's own selector further
+            let combined_selector = match selector {
+                Some(extra) => format!("{},{}", workload_selector, extra),
+                None => workload_selector,
+            };
+            (Some(workload.to_string()), Some(combined_selector), None)
         }
-    } else {
-        display::print_error_and_exit(&format!("No pod found matching pattern: {}", pod_pattern));
+        None => (None, selector.map(|s| s.to_string()), pod_pattern.map(|p| p.to_string())),
+    };
+    
+    let target_selectors = PodSelectors { labels: selector.as_deref(), fields: selectors.fields };
+    let mut pods = match &pattern {
+        _ if !target_selectors.is_empty() => pods::find_pods_by_selectors(&target_selectors, pattern.as_deref(), context, namespace),
+        // Every match is used at once, so there is no single pick to double-check against a stale cache
+        Some(pattern) if all_pods => pods::find_pods_fresh(pattern, context, namespace),
+        Some(pattern) => pods::select_pod(pattern, &target_selectors, context, namespace).into_iter().collect(),
+        None => Vec::new(),
+    };
+    
+    if pods.is_empty() {
+        display::print_error_and_exit(&format!("No pod found matching: {}", description));
     }
+    
+    // Workloads and selectors usually match several replicas - follow just one unless asked
+    if pods.len() > 1 && !all_pods {
+        let representative = pods::find_newest_pod(pattern.as_deref(), &target_selectors, context, namespace, None)
+            .filter(|name| pods.contains(name))
+            .unwrap_or_else(|| pods[0].clone());
+        display::print_info(&format!(
+            "Using representative pod {} ({} pods match, use --all-pods to see them all)",
+            representative,
+            pods.len()
+        ));
+        pods = vec![representative];
+    }
+    
+    let field_selector = selectors.fields.map(str::to_string);
+    LogsTarget { pods, workload, selector, field_selector, pattern }
+}
+
+/// Full status of the target pod when there is exactly one
+fn single_pod_info(target: &LogsTarget, context: Option<&str>, namespace: Option<&str>) -> Result<Option<k8s::Pod>, String> {
+    match target.pods.as_slice() {
+        [pod_name] => pods::get_pod(pod_name, context, namespace).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Resolve the logs target to a single pod searched in every namespace, returning the pod's namespace with it
+fn resolve_logs_target_in_all_namespaces(
+    pod_pattern: Option<&str>,
+    selectors: &PodSelectors,
+    context: Option<&str>,
+) -> (LogsTarget, Option<String>) {
+    if pod_pattern.and_then(workloads::parse_workload_target).is_some() {
+        display::print_error_and_exit("Workload targets need a namespace - use -n instead of -A");
+    }
+    
+    let pattern = pod_pattern.unwrap_or_default();
+    let Some((pod_name, namespace)) = pods::select_pod_with_namespace(pattern, selectors, context, None, true) else {
+        display::print_error_and_exit(&format!("No pod found matching: {}", selectors.describe(pod_pattern)));
+    };
+    
+    let target = LogsTarget {
+        pods: vec![pod_name],
+        workload: None,
+        selector: selectors.labels.map(str::to_string),
+        field_selector: selectors.fields.map(str::to_string),
+        pattern: pod_pattern.map(str::to_string),
+    };
+    (target, namespace)
 }
 
 /// Show enhanced header with pod and context information
-fn show_logs_header(pod_name: &str, container: Option<&str>, context: Option<&str>, namespace: Option<&str>, follow: bool, grep_pattern: Option<&str>, exclude_pattern: Option<&str>) {
+#[allow(clippy::too_many_arguments)]
+fn show_logs_header(target: &LogsTarget, pod_info: Option<&k8s::Pod>, container: Option<&str>, context: Option<&str>, namespace: Option<&str>, mode: LogsMode, filter: &LogFilter) {
     let header_line = "═".repeat(80);
+    let title = if target.pods.len() == 1 {
+        format!("{} {}", "Logs for pod:".cyan().bold(), target.pods[0].bright_white().bold())
+    } else {
+        format!("{} {}", format!("Logs for {} pods:", target.pods.len()).cyan().bold(), target.pods.join(", ").bright_white().bold())
+    };
     display::print_lines(&[
         "",
         &header_line.bright_blue().to_string(), 
-        &format!("{} {}", "Logs for pod:".cyan().bold(), pod_name.bright_white().bold()),
+        &title,
     ]);
     
     // Additional info
     let mut info_parts = Vec::new();
     
+    if let Some(workload) = &target.workload {
+        info_parts.push(format!("🧩 Workload: {}", workload.bright_white()));
+    }
+    
+    if let Some(selector) = &target.selector {
+        info_parts.push(format!("🔖 Selector: {}", selector.bright_white()));
+    }
+    if let Some(field_selector) = &target.field_selector {
+        info_parts.push(format!("🔖 Field selector: {}", field_selector.bright_white()));
+    }
+    
     if let Some(container_name) = container {
         info_parts.push(format!("🏷️  Container: {}", container_name.bright_white()));
     }
@@ -112,12 +330,21 @@ fn show_logs_header(pod_name: &str, container: Option<&str>, context: Option<&st
     }
     
     // Display filtering info if any patterns are provided
-    if let Some(grep) = grep_pattern {
-        info_parts.push(format!("🔍 Grep: {}", grep.bright_green()));
+    if let Some(grep) = &filter.grep {
+        info_parts.push(format!("🔍 Grep: {}", grep.as_str().bright_green()));
+    }
+    
+    if let Some(exclude) = &filter.exclude {
+        info_parts.push(format!("❌ Exclude: {}", exclude.as_str().bright_red()));
     }
     
-    if let Some(exclude) = exclude_pattern {
-        info_parts.push(format!("❌ Exclude: {}", exclude.bright_red()));
+    if !filter.highlights.is_empty() {
+        info_parts.push(format!("🎨 Highlights: {} rule(s)", filter.highlights.len().to_string().bright_white()));
+    }
+    
+    // Container restarts, crash loops and last termination details
+    if let Some(pod) = pod_info {
+        info_parts.extend(describe_container_states(pod, container));
     }
     
     // Display additional info
@@ -126,13 +353,19 @@ fn show_logs_header(pod_name: &str, container: Option<&str>, context: Option<&st
     }
     
     // Show mode and helpful tips
-    if follow {
-        display::print_lines(&[
-            &format!("🔄 {} {}", "Mode:".cyan(), "Following (live)".bright_green().bold()),
-            &format!("💡 {} {}", "Tip:".yellow(), "Press Ctrl+C to stop following".bright_black()),
-        ]);
-    } else {
-        display::print_line(&format!("📄 {} {}", "Mode:".cyan(), "Static view".bright_blue()));
+    match mode {
+        LogsMode::Follow => {
+            display::print_lines(&[
+                &format!("🔄 {} {}", "Mode:".cyan(), "Following (live)".bright_green().bold()),
+                &format!("💡 {} {}", "Tip:".yellow(), "Press Ctrl+C to stop following".bright_black()),
+            ]);
+        }
+        LogsMode::Static => {
+            display::print_line(&format!("📄 {} {}", "Mode:".cyan(), "Static view".bright_blue()));
+        }
+        LogsMode::Stats => {
+            display::print_line(&format!("📊 {} {}", "Mode:".cyan(), "Statistics summary".bright_magenta()));
+        }
     }
     
     display::print_lines(&[
@@ -142,29 +375,168 @@ fn show_logs_header(pod_name: &str, container: Option<&str>, context: Option<&st
 }
 
 /// Execute logs command with filtering for static output
-fn execute_logs_with_filtering(args: &[&str], context: Option<&str>, namespace: Option<&str>, grep_regex: Option<Regex>, exclude_regex: Option<Regex>) {
-    let kubectl_args = kubectl::build_args(args, context, namespace);
-    let args_refs: Vec<&str> = kubectl_args.iter().map(|s| s.as_str()).collect();
-    
-    match kubectl::execute_kubectl(&args_refs) {
-        Ok(output) => {
-            let mut line_num = 1;
-            for line in output.lines() {
-                if should_show_line(line, &grep_regex, &exclude_regex) {
-                    print_line_with_number(line, line_num);
-                    line_num += 1;
+/// Returns the number of log lines received, or the kubectl error for a single pod
+fn execute_logs_with_filtering(pod_names: &[String], source: &LogsSource, filter: &LogFilter) -> Result<usize, String> {
+    let mut line_num = 1;
+    let mut lines_received = 0;
+    for pod_name in pod_names {
+        let args = source.build_args(pod_name, false, None);
+        let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        
+        match kubectl::execute_with_context(&args_refs, source.context, source.namespace) {
+            Ok(output) => {
+                for line in output.lines() {
+                    lines_received += 1;
+                    if filter.should_show(line) {
+                        print_line_with_number(&filter.highlight(line), line_num, source.prefix_pods.then_some(pod_name));
+                        line_num += 1;
+                    }
                 }
             }
+            Err(error) if pod_names.len() > 1 => {
+                display::print_error(&format!("Failed to get logs for {}: {}", pod_name, error.trim()));
+            }
+            Err(error) => return Err(error),
         }
-        Err(error) => {
-            display::print_error_and_exit(&format!("Failed to get logs: {}", error));
+    }
+    Ok(lines_received)
+}
+
+/// Execute logs command and print a statistics summary instead of the lines
+fn execute_logs_stats(pod_names: &[String], source: &LogsSource, filter: &LogFilter, top: usize) {
+    let mut collector = LogStatsCollector::new();
+    for pod_name in pod_names {
+        let args = source.build_args(pod_name, false, None);
+        let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        
+        match kubectl::execute_with_context(&args_refs, source.context, source.namespace) {
+            Ok(output) => {
+                for line in output.lines() {
+                    let (timestamp, content) = split_timestamp(line);
+                    if filter.should_show(content) {
+                        collector.add(timestamp, content);
+                    }
+                }
+            }
+            Err(error) if pod_names.len() > 1 => {
+                display::print_error(&format!("Failed to get logs for {}: {}", pod_name, error.trim()));
+            }
+            Err(error) => {
+                display::print_error_and_exit(&format!("Failed to get logs: {}", error));
+            }
         }
     }
+    let stats = collector.finish(top);
+    display::print_log_stats(
+        &stats.summary_rows(),
+        &stats.level_rows(),
+        &stats.message_rows(),
+        &stats.lines_per_bucket,
+        stats.minutes_per_bucket,
+    );
+}
+
+/// Follow logs of the target pod(s), one stream per pod
+fn follow_logs(target: &LogsTarget, source: &LogsSource, filter: &LogFilter) {
+    if let [pod_name] = target.pods.as_slice() {
+        let replicas = (target.pattern.as_deref(), target.selectors());
+        if let Err(error) = execute_logs_with_filtering_streaming(pod_name, Some(replicas), source, filter) {
+            display::print_error_and_exit(&error);
+        }
+        return;
+    }
+    
+    // Several pods: stream them side by side, without switching to replacement replicas
+    thread::scope(|scope| {
+        for pod_name in &target.pods {
+            scope.spawn(move || {
+                if let Err(error) = execute_logs_with_filtering_streaming(pod_name, None, source, filter) {
+                    display::print_error(&format!("{}: {}", pod_name, error));
+                }
+            });
+        }
+    });
 }
 
 /// Execute logs command with streaming filtering for follow mode
-fn execute_logs_with_filtering_streaming(args: &[&str], context: Option<&str>, namespace: Option<&str>, grep_regex: Option<Regex>, exclude_regex: Option<Regex>) {
-    let kubectl_args = kubectl::build_args(args, context, namespace);
+/// Reconnects when the stream ends (API timeouts, container restarts, pod replacement).
+/// `replicas` is the (name pattern, selectors) used to find a replacement pod if this one disappears.
+fn execute_logs_with_filtering_streaming(
+    pod_name: &str,
+    replicas: Option<(Option<&str>, PodSelectors)>,
+    source: &LogsSource,
+    filter: &LogFilter,
+) -> Result<(), String> {
+    let mut pod_name = pod_name.to_string();
+    let mut last_timestamp: Option<String> = None;
+    let mut line_num = 1;
+    let mut last_progress = Instant::now();
+    
+    loop {
+        let args = source.build_args(&pod_name, true, last_timestamp.as_deref());
+        let (lines_received, error) = stream_logs(&pod_name, &args, source, filter, &mut line_num, &mut last_timestamp);
+        
+        // Logs of a previous container never grow, so there is nothing to reconnect to
+        if source.previous {
+            return match error {
+                Some(error) => Err(format!("Failed to get logs: {}", error)),
+                None => Ok(()),
+            };
+        }
+        
+        if lines_received > 0 {
+            last_progress = Instant::now();
+        }
+        
+        if last_progress.elapsed() >= GIVE_UP_AFTER {
+            let reason = error.unwrap_or_else(|| "no new output".to_string());
+            let waited = time::format_duration(GIVE_UP_AFTER.as_secs() as i64);
+            return Err(format!("Giving up after {} without new output: {}", waited, reason.trim()));
+        }
+        
+        print_stream_marker(&format!("🔌 Log stream for {} ended, reconnecting...", pod_name));
+        thread::sleep(RECONNECT_DELAY);
+        
+        // The pod may have been replaced, e.g. during a rollout
+        if !pods::is_pod_active(&pod_name, source.context, source.namespace) {
+            let Some((pattern, selectors)) = replicas else {
+                print_stream_marker(&format!("🔌 Pod {} is gone", pod_name));
+                return Ok(());
+            };
+            
+            match pods::find_newest_pod(pattern, &selectors, source.context, source.namespace, Some(&pod_name)) {
+                Some(replacement) => {
+                    print_stream_marker(&format!("🔌 Pod {} is gone, switching to {}", pod_name, replacement));
+                    pod_name = replacement;
+                    last_progress = Instant::now();
+                }
+                None => {
+                    let wanted = selectors.describe(pattern);
+                    print_stream_marker(&format!("🔌 Pod {} is gone, waiting for a pod matching '{}'...", pod_name, wanted));
+                    continue;
+                }
+            }
+        }
+        
+        match &last_timestamp {
+            Some(since) => print_stream_marker(&format!("🔌 Reconnected to {} (since {})", pod_name, since)),
+            None => print_stream_marker(&format!("🔌 Reconnected to {}", pod_name)),
+        }
+    }
+}
+
+/// Stream a single kubectl logs invocation, printing new lines until the process exits
+/// Returns the number of new lines received and the kubectl error output if it failed
+fn stream_logs(
+    pod_name: &str,
+    args: &[String],
+    source: &LogsSource,
+    filter: &LogFilter,
+    line_num: &mut usize,
+    last_timestamp: &mut Option<String>,
+) -> (usize, Option<String>) {
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let kubectl_args = kubectl::build_args(&args_refs, source.context, source.namespace);
     
     let mut cmd = Command::new("kubectl");
     cmd.args(&kubectl_args);
@@ -177,46 +549,405 @@ fn execute_logs_with_filtering_streaming(args: &[&str], context: Option<&str>, n
     let stdout = child.stdout.take()
         .unwrap_or_else(|| display::print_error_and_exit("Failed to capture kubectl stdout"));
     
+    // Drain stderr concurrently so kubectl never blocks on a full stderr pipe while we read stdout
+    let stderr = child.stderr.take()
+        .unwrap_or_else(|| display::print_error_and_exit("Failed to capture kubectl stderr"));
+    let stderr_reader = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = BufReader::new(stderr).read_to_string(&mut output);
+        output
+    });
+    
     let reader = BufReader::new(stdout);
-    let mut line_num = 1;
+    let last_seen = last_timestamp.as_deref().and_then(time::parse_rfc3339);
+    let mut lines_received = 0;
     
     for line in reader.lines() {
         match line {
             Ok(line_content) => {
-                if should_show_line(&line_content, &grep_regex, &exclude_regex) {
-                    print_line_with_number(&line_content, line_num);
-                    line_num += 1;
+                let (timestamp, content) = split_timestamp(&line_content);
+                
+                // --since-time has second precision, so skip lines we have already shown
+                if let (Some(last), Some(current)) = (last_seen, timestamp.and_then(time::parse_rfc3339))
+                    && current <= last {
+                    continue;
+                }
+                
+                if let Some(timestamp) = timestamp {
+                    *last_timestamp = Some(timestamp.to_string());
+                }
+                lines_received += 1;
+                
+                if filter.should_show(content) {
+                    print_line_with_number(&filter.highlight(content), *line_num, source.prefix_pods.then_some(pod_name));
+                    *line_num += 1;
                 }
             }
             Err(_) => break,
         }
     }
     
-    let _ = child.wait();
+    let status = child.wait();
+    let stderr_output = stderr_reader.join().unwrap_or_default();
+    match status {
+        Ok(status) if !status.success() => (lines_received, Some(stderr_output)),
+        Ok(_) => (lines_received, None),
+        Err(e) => (lines_received, Some(e.to_string())),
+    }
+}
+
+/// Check whether the relevant container has a previous (terminated) instance with logs
+fn has_previous_container(pod: &k8s::Pod, container: Option<&str>) -> bool {
+    relevant_container_statuses(pod, container)
+        .iter()
+        .any(|status| status.restart_count > 0 || status.last_state.terminated.is_some())
+}
+
+/// Container statuses relevant to the logs view: the requested container, or all of them
+fn relevant_container_statuses<'a>(pod: &'a k8s::Pod, container: Option<&str>) -> Vec<&'a k8s::ContainerStatus> {
+    pod.status.container_statuses
+        .iter()
+        .filter(|status| container.is_none_or(|name| status.name == name))
+        .collect()
+}
+
+/// Describe waiting states and last terminations of the relevant containers for the header
+fn describe_container_states(pod: &k8s::Pod, container: Option<&str>) -> Vec<String> {
+    let mut lines = Vec::new();
+    
+    for status in relevant_container_statuses(pod, container) {
+        if let Some(waiting) = &status.state.waiting {
+            let reason = waiting.reason.as_deref().unwrap_or("Waiting");
+            let mut line = format!("⏳ {} {}", format!("{}:", status.name).bright_white(), reason.bright_red().bold());
+            if let Some(message) = &waiting.message {
+                line.push_str(&format!(" - {}", message.bright_black()));
+            }
+            lines.push(line);
+        }
+        
+        if let Some(terminated) = &status.last_state.terminated {
+            let reason = terminated.reason.as_deref().unwrap_or("Unknown");
+            lines.push(format!(
+                "💥 {} last terminated: {} (exit code {}), restarts: {}",
+                format!("{}:", status.name).bright_white(),
+                reason.bright_red().bold(),
+                terminated.exit_code.to_string().bright_red(),
+                status.restart_count.to_string().bright_yellow(),
+            ));
+            
+            let started = terminated.started_at.as_deref().unwrap_or("?");
+            let finished = terminated.finished_at.as_deref().unwrap_or("?");
+            let ran_for = time::seconds_between(started, finished)
+                .map(|seconds| format!(" (ran {})", time::format_duration(seconds)))
+                .unwrap_or_default();
+            lines.push(format!("   🕒 Started: {}  Finished: {}{}", started.bright_white(), finished.bright_white(), ran_for));
+        }
+    }
+    
+    lines
+}
+
+/// Split the RFC3339 timestamp added by `kubectl logs --timestamps` from the log line
+fn split_timestamp(line: &str) -> (Option<&str>, &str) {
+    match line.split_once(' ') {
+        Some((timestamp, content)) if time::parse_rfc3339(timestamp).is_some() => (Some(timestamp), content),
+        _ => (None, line),
+    }
+}
+
+/// Print a visible marker between log stream segments
+fn print_stream_marker(message: &str) {
+    display::print_line(&format!("{} {} {}", "────".yellow(), message.yellow().bold(), "────".yellow()));
+}
+
+/// Grep/exclude filters and highlight rules applied to every log line
+struct LogFilter {
+    grep: Option<Regex>,
+    exclude: Option<Regex>,
+    highlights: Vec<(Regex, DynColors)>,
 }
 
-/// Determine if a line should be shown based on grep and exclude patterns
-fn should_show_line(line: &str, grep_regex: &Option<Regex>, exclude_regex: &Option<Regex>) -> bool {
-    // First check exclude pattern - if it matches, don't show the line
-    if let Some(exclude) = exclude_regex {
-        if exclude.is_match(line) {
+impl LogFilter {
+    /// Compile the grep/exclude patterns and the configured highlight rules
+    /// Exits on invalid grep/exclude patterns, skips invalid highlight rules with a warning
+    fn new(grep_pattern: Option<&str>, exclude_pattern: Option<&str>, highlight_rules: &HashMap<String, String>) -> Self {
+        let grep = grep_pattern.map(|pattern| {
+            match Regex::new(pattern) {
+                Ok(regex) => regex,
+                Err(e) => {
+                    display::print_error_and_exit(&format!("Invalid grep pattern '{}': {}", pattern, e));
+                }
+            }
+        });
+        
+        let exclude = exclude_pattern.map(|pattern| {
+            match Regex::new(pattern) {
+                Ok(regex) => regex,
+                Err(e) => {
+                    display::print_error_and_exit(&format!("Invalid exclude pattern '{}': {}", pattern, e));
+                }
+            }
+        });
+        
+        // Sort rules so overlapping highlights resolve the same way on every run
+        let mut rules: Vec<(&String, &String)> = highlight_rules.iter().collect();
+        rules.sort();
+        
+        let highlights = rules
+            .into_iter()
+            .filter_map(|(pattern, color)| {
+                match (Regex::new(pattern), color.parse::<DynColors>()) {
+                    (Ok(regex), Ok(color)) => Some((regex, color)),
+                    _ => {
+                        display::print_warning(&format!("Skipping invalid highlight rule '{}' = '{}'", pattern, color));
+                        None
+                    }
+                }
+            })
+            .collect();
+        
+        Self { grep, exclude, highlights }
+    }
+    
+    /// Determine if a line should be shown based on grep and exclude patterns
+    fn should_show(&self, line: &str) -> bool {
+        // First check exclude pattern - if it matches, don't show the line
+        if let Some(exclude) = &self.exclude
+            && exclude.is_match(line) {
             return false;
         }
+        
+        // Then check grep pattern - if provided, line must match to be shown
+        if let Some(grep) = &self.grep {
+            grep.is_match(line)
+        } else {
+            // No grep pattern, show the line (unless excluded above)
+            true
+        }
     }
     
-    // Then check grep pattern - if provided, line must match to be shown
-    if let Some(grep) = grep_regex {
-        grep.is_match(line)
-    } else {
-        // No grep pattern, show the line (unless excluded above)
-        true
+    /// Highlight grep matches and highlight rule matches inside a line
+    /// Grep matches take precedence over highlight rules where they overlap
+    fn highlight(&self, line: &str) -> String {
+        if self.grep.is_none() && self.highlights.is_empty() {
+            return line.to_string();
+        }
+        
+        // Collect spans in priority order: grep matches first, then highlight rules
+        let mut candidates: Vec<(usize, usize, Option<DynColors>)> = Vec::new();
+        if let Some(grep) = &self.grep {
+            candidates.extend(grep.find_iter(line).map(|m| (m.start(), m.end(), None)));
+        }
+        for (regex, color) in &self.highlights {
+            candidates.extend(regex.find_iter(line).map(|m| (m.start(), m.end(), Some(*color))));
+        }
+        
+        // Keep only non-empty spans that don't overlap a higher priority span
+        let mut spans: Vec<(usize, usize, Option<DynColors>)> = Vec::new();
+        for (start, end, color) in candidates {
+            if start < end && spans.iter().all(|(s, e, _)| end <= *s || start >= *e) {
+                spans.push((start, end, color));
+            }
+        }
+        spans.sort_by_key(|(start, _, _)| *start);
+        
+        let mut result = String::with_capacity(line.len());
+        let mut position = 0;
+        for (start, end, color) in spans {
+            result.push_str(&line[position..start]);
+            let text = &line[start..end];
+            match color {
+                Some(color) => result.push_str(&text.color(color).bold().to_string()),
+                None => result.push_str(&text.black().on_bright_yellow().to_string()),
+            }
+            position = end;
+        }
+        result.push_str(&line[position..]);
+        result
+    }
+}
+
+/// Summary of a log stream produced by `--stats`
+struct LogStats {
+    total_lines: usize,
+    /// Line counts per detected level, most frequent first
+    level_counts: Vec<(String, usize)>,
+    /// Most frequent normalised messages with their counts
+    top_messages: Vec<(usize, String)>,
+    /// Line counts per time bucket, oldest first
+    lines_per_bucket: Vec<usize>,
+    minutes_per_bucket: usize,
+    first_timestamp: Option<String>,
+    last_timestamp: Option<String>,
+}
+
+impl LogStats {
+    /// Total line count and the covered time range
+    fn summary_rows(&self) -> Vec<LogStatDisplay> {
+        let mut rows = vec![LogStatDisplay { metric: "Total lines".to_string(), value: self.total_lines.to_string() }];
+        if let Some(first) = &self.first_timestamp {
+            rows.push(LogStatDisplay { metric: "First timestamp".to_string(), value: first.to_string() });
+        }
+        if let Some(last) = &self.last_timestamp {
+            rows.push(LogStatDisplay { metric: "Last timestamp".to_string(), value: last.to_string() });
+        }
+        rows
+    }
+    
+    /// Line count and share per level
+    fn level_rows(&self) -> Vec<LogLevelDisplay> {
+        self.level_counts
+            .iter()
+            .map(|(level, count)| LogLevelDisplay {
+                level: level.to_string(),
+                count: count.to_string(),
+                share: format!("{:.1}%", *count as f64 * 100.0 / self.total_lines as f64),
+            })
+            .collect()
+    }
+    
+    /// The most frequent messages with their counts
+    fn message_rows(&self) -> Vec<LogMessageDisplay> {
+        self.top_messages
+            .iter()
+            .map(|(count, message)| LogMessageDisplay {
+                count: count.to_string(),
+                message: display::truncate(message, 100),
+            })
+            .collect()
+    }
+}
+
+/// Accumulates log lines into a LogStats summary
+struct LogStatsCollector {
+    level_regex: Regex,
+    uuid_regex: Regex,
+    hex_regex: Regex,
+    number_regex: Regex,
+    total_lines: usize,
+    level_counts: HashMap<String, usize>,
+    message_counts: HashMap<String, usize>,
+    minute_counts: HashMap<i64, usize>,
+    first_timestamp: Option<String>,
+    last_timestamp: Option<String>,
+}
+
+impl LogStatsCollector {
+    /// Maximum number of sparkline buckets; longer ranges are merged into wider buckets
+    const MAX_BUCKETS: usize = 60;
+    
+    fn new() -> Self {
+        Self {
+            level_regex: Regex::new(LEVEL_PATTERN).expect("valid level regex"),
+            uuid_regex: Regex::new(r"(?i)\b[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\b").expect("valid uuid regex"),
+            hex_regex: Regex::new(r"(?i)\b(0x[0-9a-f]+|[0-9a-f]{6,})\b").expect("valid hex regex"),
+            number_regex: Regex::new(r"\d+(\.\d+)?").expect("valid number regex"),
+            total_lines: 0,
+            level_counts: HashMap::new(),
+            message_counts: HashMap::new(),
+            minute_counts: HashMap::new(),
+            first_timestamp: None,
+            last_timestamp: None,
+        }
+    }
+    
+    /// Record a single (already filtered) log line
+    fn add(&mut self, timestamp: Option<&str>, content: &str) {
+        self.total_lines += 1;
+        
+        let level = self.level_regex
+            .captures(content)
+            .map(|captures| normalize_level(&captures[1]))
+            .unwrap_or("NONE");
+        *self.level_counts.entry(level.to_string()).or_insert(0) += 1;
+        
+        *self.message_counts.entry(self.normalize_message(content)).or_insert(0) += 1;
+        
+        if let Some(timestamp) = timestamp {
+            if let Some((seconds, _)) = time::parse_rfc3339(timestamp) {
+                *self.minute_counts.entry(seconds.div_euclid(60)).or_insert(0) += 1;
+            }
+            if self.first_timestamp.is_none() {
+                self.first_timestamp = Some(timestamp.to_string());
+            }
+            self.last_timestamp = Some(timestamp.to_string());
+        }
+    }
+    
+    /// Mask UUIDs, hex identifiers and numbers so similar messages group together
+    fn normalize_message(&self, content: &str) -> String {
+        let masked = self.uuid_regex.replace_all(content.trim(), "<uuid>");
+        let masked = self.hex_regex.replace_all(&masked, |captures: &regex::Captures| {
+            // Only mask tokens that mix digits and letters, so plain words and numbers survive
+            let token = &captures[0];
+            let has_digit = token.chars().any(|c| c.is_ascii_digit());
+            let has_letter = token.chars().any(|c| c.is_ascii_alphabetic());
+            if has_digit && has_letter {
+                "<hex>".to_string()
+            } else {
+                token.to_string()
+            }
+        });
+        let masked = self.number_regex.replace_all(&masked, "<n>");
+        masked.to_string()
+    }
+    
+    /// Produce the final summary keeping the `top` most frequent messages
+    fn finish(self, top: usize) -> LogStats {
+        let mut level_counts: Vec<(String, usize)> = self.level_counts.into_iter().collect();
+        level_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        
+        let mut top_messages: Vec<(usize, String)> = self.message_counts
+            .into_iter()
+            .map(|(message, count)| (count, message))
+            .collect();
+        top_messages.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        top_messages.truncate(top);
+        
+        // Spread per-minute counts over at most MAX_BUCKETS buckets, keeping empty minutes
+        let mut lines_per_bucket = Vec::new();
+        let mut minutes_per_bucket = 1;
+        if let (Some(first), Some(last)) = (self.minute_counts.keys().min(), self.minute_counts.keys().max()) {
+            let minutes = (last - first + 1) as usize;
+            minutes_per_bucket = minutes.div_ceil(Self::MAX_BUCKETS);
+            lines_per_bucket = vec![0; minutes.div_ceil(minutes_per_bucket)];
+            for (minute, count) in &self.minute_counts {
+                lines_per_bucket[(minute - first) as usize / minutes_per_bucket] += count;
+            }
+        }
+        
+        LogStats {
+            total_lines: self.total_lines,
+            level_counts,
+            top_messages,
+            lines_per_bucket,
+            minutes_per_bucket,
+            first_timestamp: self.first_timestamp,
+            last_timestamp: self.last_timestamp,
+        }
+    }
+}
+
+/// Map level keyword variants to a canonical level name
+fn normalize_level(level: &str) -> &'static str {
+    match level.to_lowercase().as_str() {
+        "trace" => "TRACE",
+        "debug" => "DEBUG",
+        "info" | "notice" => "INFO",
+        "warn" | "warning" => "WARN",
+        "error" | "err" => "ERROR",
+        _ => "FATAL",
     }
 }
 
 /// Print a line with simple visual differentiation
-fn print_line_with_number(line: &str, line_num: usize) {
-    let line_prefix = format!("{:4} │ ", line_num);
+/// When several pods are shown, the line is prefixed with the pod name
+fn print_line_with_number(line: &str, line_num: usize, pod_name: Option<&str>) {
+    let line_prefix = match pod_name {
+        Some(pod) => format!("{} {:4} │ ", format!("[{}]", pod).magenta(), line_num.cyan().bold()),
+        None => format!("{:4} │ ", line_num).cyan().bold().to_string(),
+    };
 
-    display::print_line(&format!("{}{}\n", line_prefix.cyan().bold(), line));
+    display::print_line(&format!("{}{}\n", line_prefix, line));
     
 } 
\ No newline at end of file
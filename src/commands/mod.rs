@@ -1,15 +1,34 @@
 pub mod config;
 pub mod context;
+pub mod context_edit;
 pub mod pods;
+pub mod pods_watch;
+pub mod pod_usage;
 pub mod namespace;
 pub mod exec;
 pub mod logs;
+pub mod workloads;
+pub mod protection;
+pub mod audit;
+pub mod doctor;
+pub mod describe;
+pub mod events;
+pub mod delete;
+pub mod rollout;
+pub mod scale;
 
 // Re-export main functions for clean imports
 pub use config::handle_config_command;
-pub use context::{handle_ctx_command, resolve_context_pattern};
+pub use context::{handle_ctx_command, handle_ctx_subcommand, resolve_context_pattern};
 pub use pods::handle_pods_command;
-pub use namespace::resolve_namespace_pattern;
+pub use namespace::{handle_ns_command, resolve_namespace_pattern};
 pub use exec::handle_exec_command;
 pub use logs::handle_logs_command;
+pub use audit::handle_audit_command;
+pub use doctor::handle_doctor_command;
+pub use describe::handle_describe_command;
+pub use events::handle_events_command;
+pub use delete::handle_delete_command;
+pub use rollout::{handle_restart_command, handle_rollout_command, handle_rollout_undo_command};
+pub use scale::handle_scale_command;
  
\ No newline at end of file
@@ -1,23 +1,41 @@
-use crate::{display, utils, kubectl};
+use crate::{display, utils, kubectl, session, audit, cache};
+use crate::audit::AuditEntry;
+use crate::commands::context::get_current_context;
+use crate::commands::protection;
+use crate::history::History;
+use crate::kubeconfig::KubeConfig;
+
+/// Namespace kubectl uses when a context has none set
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+/// Handle the ns command - list namespaces, or set the current context's default namespace
+/// by pattern ("-" for the previous one)
+pub fn handle_ns_command(name_pattern: Option<&str>) {
+    protection::warn_if_protected(None);
+    match name_pattern {
+        None => list_namespaces_with_current(),
+        Some("-") => switch_to_previous_namespace(),
+        Some(pattern) => switch_to_namespace_by_pattern(pattern),
+    }
+}
 
 /// Resolve a namespace pattern to an exact namespace name
 /// Returns None if user cancels, exits process if no matches found
 pub fn resolve_namespace_pattern(pattern: &str, context: Option<&str>) -> Option<String> {
     display::print_working(&format!("Resolving namespace with pattern {}...", pattern));
-    // Get all namespaces
-    let namespaces = match get_all_namespaces(context) {
-        Ok(namespaces) => namespaces,
+    // Find matching namespaces
+    let matches = cache::matching_names(
+        &cache::namespaces_key(context),
+        || get_all_namespaces(context),
+        |namespace| namespace.contains(pattern),
+    );
+    let matches = match matches {
+        Ok(matches) => matches,
         Err(error) => {
             display::print_error_and_exit(&format!("Error getting namespaces: {}", error));
         }
     };
     
-    // Find matching namespaces
-    let matches: Vec<String> = namespaces
-        .into_iter()
-        .filter(|namespace| namespace.contains(pattern))
-        .collect();
-    
     let resolved_namespace = utils::select_from_matches(matches, pattern, "namespace");
     if let Some(namespace) = &resolved_namespace {
         display::print_working(&format!("Using namespace: {}", namespace));
@@ -25,8 +43,95 @@ pub fn resolve_namespace_pattern(pattern: &str, context: Option<&str>) -> Option
     resolved_namespace
 }
 
+/// List all namespaces of the current context and mark its default namespace
+pub fn list_namespaces_with_current() {
+    let current_namespace = get_current_namespace();
+    
+    display::print_working("Listing namespaces...");
+    match get_all_namespaces(None) {
+        Ok(namespaces) => {
+            display::print_namespaces_table(&namespaces, &current_namespace);
+        }
+        Err(error) => {
+            display::print_error_and_exit(&format!("Error listing namespaces: {}", error));
+        }
+    }
+}
+
+/// Switch the default namespace by pattern, preferring an exact name match
+pub fn switch_to_namespace_by_pattern(pattern: &str) {
+    let exact = cache::matching_names(&cache::namespaces_key(None), || get_all_namespaces(None), |namespace| namespace == pattern);
+    let resolved_namespace = match exact {
+        Ok(matches) if !matches.is_empty() => Some(pattern.to_string()),
+        _ => resolve_namespace_pattern(pattern, None),
+    };
+    
+    match resolved_namespace {
+        Some(namespace) => use_namespace(&namespace),
+        None => display::print_error("Operation cancelled."),
+    }
+}
+
+/// Switch back to the previously used namespace of the current context
+pub fn switch_to_previous_namespace() {
+    let context = current_context_or_exit();
+    let current_namespace = get_current_namespace();
+    match History::load().previous_namespace(&context, Some(&current_namespace)) {
+        Some(previous) => use_namespace(&previous),
+        None => display::print_error_and_exit(&format!("No previous namespace in history for context {}", context)),
+    }
+}
+
+/// Set the default namespace of the current context
+/// Inside a shell session only the session kubeconfig is changed, never the shared one
+pub fn use_namespace(namespace: &str) {
+    let context = current_context_or_exit();
+    let previous_namespace = get_current_namespace();
+    let namespace_arg = format!("--namespace={}", namespace);
+    let result = match session::active_session() {
+        Some(session_file) => session::set_session_namespace(&session_file, namespace)
+            .map(|_| format!("Default namespace for {} set to: {} (this shell session only)", context, namespace)),
+        None => kubectl::execute_kubectl(&["config", "set-context", "--current", &namespace_arg])
+            .map(|_| format!("Default namespace for {} set to: {}", context, namespace)),
+    };
+    
+    match result {
+        Ok(message) => {
+            display::print_success(&message);
+            audit::record(AuditEntry::new("ns", Some(&context), Some(namespace)).exit_code(Some(0)));
+            let mut history = History::load();
+            history.record_namespace(&context, Some(&previous_namespace), namespace);
+            if let Err(err) = history.save() {
+                display::print_warning(&err);
+            }
+        }
+        Err(error) => {
+            display::print_error_and_exit(&format!("Error switching namespace: {}", error));
+        }
+    }
+}
+
+/// Get the default namespace of the current context ("default" when none is set)
+pub fn get_current_namespace() -> String {
+    let kubeconfig = KubeConfig::load().ok();
+    kubeconfig
+        .as_ref()
+        .and_then(|config| config.context(&config.current_context))
+        .and_then(|entry| entry.namespace.clone())
+        .filter(|namespace| !namespace.is_empty())
+        .unwrap_or_else(|| DEFAULT_NAMESPACE.to_string())
+}
+
+/// The current context, exiting if none is set
+fn current_context_or_exit() -> String {
+    match get_current_context() {
+        Some(context) if !context.is_empty() => context,
+        _ => display::print_error_and_exit("No current context set - use `kubix ctx <name>` first"),
+    }
+}
+
 /// Get all available namespaces as a vector
-fn get_all_namespaces(context: Option<&str>) -> Result<Vec<String>, String> {
+pub fn get_all_namespaces(context: Option<&str>) -> Result<Vec<String>, String> {
     let args = vec!["get", "namespaces", "-o", "name"];
     let mut cmd_args = Vec::new();
     
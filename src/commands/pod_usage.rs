@@ -0,0 +1,149 @@
+use crate::{kubectl, display, k8s, quantity};
+use crate::commands::pods::PodSelectors;
+use crate::display::PodDisplay;
+use std::collections::HashMap;
+
+/// Usage above this share of the limit is highlighted
+const LIMIT_WARNING_RATIO: f64 = 0.8;
+
+/// Current CPU and memory usage of a pod, summed over its containers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PodUsage {
+    pub cpu_millis: f64,
+    pub memory_bytes: f64,
+}
+
+/// Current usage of every pod in a namespace (or of every namespace), keyed by "namespace/name"
+/// Only the label selector is applied - the metrics API rejects pod field selectors such as `spec.nodeName`,
+/// and extra entries do no harm since usage is looked up per pod.
+/// Fails when metrics-server (the metrics.k8s.io API) is not available
+pub fn get_pod_usage(
+    context: Option<&str>,
+    namespace: Option<&str>,
+    selectors: &PodSelectors,
+    all_namespaces: bool,
+) -> Result<HashMap<String, PodUsage>, String> {
+    let mut base_args = vec!["get", "pods.metrics.k8s.io", "-o", "json"];
+    if let Some(labels) = selectors.labels {
+        base_args.extend(["-l", labels]);
+    }
+    if all_namespaces {
+        base_args.push("--all-namespaces");
+    }
+    let output = kubectl::execute_with_context(&base_args, context, namespace)?;
+    let metrics = k8s::parse::<k8s::PodMetricsList>(&output, "pod metrics")?;
+
+    Ok(metrics
+        .items
+        .into_iter()
+        .map(|pod| {
+            let usage = pod.containers.iter().fold(PodUsage::default(), |total, container| PodUsage {
+                cpu_millis: total.cpu_millis + usage_of(container, "cpu", quantity::parse_cpu_millis),
+                memory_bytes: total.memory_bytes + usage_of(container, "memory", quantity::parse_memory_bytes),
+            });
+            (pod.metadata.key(), usage)
+        })
+        .collect())
+}
+
+/// Fetch usage for a listing, warning instead of failing when metrics-server is missing
+pub fn get_pod_usage_or_warn(
+    context: Option<&str>,
+    namespace: Option<&str>,
+    selectors: &PodSelectors,
+    all_namespaces: bool,
+) -> HashMap<String, PodUsage> {
+    match get_pod_usage(context, namespace, selectors, all_namespaces) {
+        Ok(usage) => usage,
+        Err(error) => {
+            let prefix = context.map(|context| format!("{}: ", context)).unwrap_or_default();
+            display::print_warning(&format!(
+                "{}Metrics API not available (is metrics-server installed?) - showing requests and limits only: {}",
+                prefix,
+                error.trim()
+            ));
+            HashMap::new()
+        }
+    }
+}
+
+/// Usage of a pod, if metrics-server reported it
+pub fn usage_of_pod<'a>(usage: &'a HashMap<String, PodUsage>, pod: &k8s::Pod) -> Option<&'a PodUsage> {
+    usage.get(&pod.metadata.key())
+}
+
+/// Fill the usage columns of a pod's row, flagging usage close to the limit
+pub fn fill_usage_columns(row: &mut PodDisplay, pod: &k8s::Pod, usage: Option<&PodUsage>) {
+    let cpu_requests = pod_resource(pod, false, "cpu", quantity::parse_cpu_millis);
+    let cpu_limit = pod_resource(pod, true, "cpu", quantity::parse_cpu_millis);
+    let memory_requests = pod_resource(pod, false, "memory", quantity::parse_memory_bytes);
+    let memory_limit = pod_resource(pod, true, "memory", quantity::parse_memory_bytes);
+
+    row.cpu_requests = format_requests(cpu_requests, cpu_limit, quantity::format_cpu_millis);
+    row.memory_requests = format_requests(memory_requests, memory_limit, quantity::format_memory_bytes);
+
+    if let Some(usage) = usage {
+        (row.cpu, row.cpu_hot) = format_usage(usage.cpu_millis, cpu_limit, quantity::format_cpu_millis);
+        (row.memory, row.memory_hot) = format_usage(usage.memory_bytes, memory_limit, quantity::format_memory_bytes);
+    }
+}
+
+/// Sort pods by their current CPU or memory usage, heaviest first; pods without metrics go last
+/// `usage_for` looks up the usage of a pod by its tag (e.g. its context) and the pod itself
+pub fn sort_by_usage<T>(
+    pods: &mut [(T, &k8s::Pod)],
+    resource: &str,
+    usage_for: impl Fn(&T, &k8s::Pod) -> Option<PodUsage>,
+) {
+    let value = |tag: &T, pod: &k8s::Pod| {
+        usage_for(tag, pod).map_or(-1.0, |usage| match resource {
+            "memory" => usage.memory_bytes,
+            _ => usage.cpu_millis,
+        })
+    };
+    pods.sort_by(|(tag_a, a), (tag_b, b)| value(tag_b, b).total_cmp(&value(tag_a, a)));
+}
+
+/// Usage of a container for a resource, 0 if missing or malformed
+fn usage_of(container: &k8s::ContainerMetrics, resource: &str, parse: fn(&str) -> Option<f64>) -> f64 {
+    container.usage.get(resource).and_then(|value| parse(value)).unwrap_or_default()
+}
+
+/// Total request or limit of a pod for a resource
+/// None if no container sets it - or, for limits, if any container is unbounded
+fn pod_resource(pod: &k8s::Pod, limits: bool, resource: &str, parse: fn(&str) -> Option<f64>) -> Option<f64> {
+    let values: Vec<Option<f64>> = pod
+        .spec
+        .containers
+        .iter()
+        .map(|container| {
+            let resources = &container.resources;
+            let quantities = if limits { &resources.limits } else { &resources.requests };
+            quantities.get(resource).and_then(|value| parse(value))
+        })
+        .collect();
+    if values.iter().all(Option::is_none) || (limits && values.iter().any(Option::is_none)) {
+        return None;
+    }
+    Some(values.iter().flatten().sum())
+}
+
+/// "request/limit" with "-" for whatever is not set
+fn format_requests(request: Option<f64>, limit: Option<f64>, format: fn(f64) -> String) -> String {
+    format!(
+        "{}/{}",
+        request.map(format).unwrap_or_else(|| "-".to_string()),
+        limit.map(format).unwrap_or_else(|| "-".to_string())
+    )
+}
+
+/// Usage with its share of the limit, and whether that share is over the warning threshold
+fn format_usage(used: f64, limit: Option<f64>, format: fn(f64) -> String) -> (String, bool) {
+    match limit.filter(|limit| *limit > 0.0) {
+        Some(limit) => {
+            let ratio = used / limit;
+            (format!("{} ({:.0}%)", format(used), ratio * 100.0), ratio > LIMIT_WARNING_RATIO)
+        }
+        None => (format(used), false),
+    }
+}
@@ -1,24 +1,114 @@
-use crate::{kubectl, utils, display};
-use crate::commands::{resolve_context_pattern, resolve_namespace_pattern};
+use crate::{kubectl, utils, display, k8s, cache, time};
+use crate::display::{PodColumns, PodDisplay};
+use crate::commands::{context, events, protection, pods_watch, pod_usage, resolve_context_pattern, resolve_namespace_pattern};
+use crate::commands::pod_usage::PodUsage;
+use std::collections::{HashMap, HashSet};
+use std::thread;
+
+/// Label and field selectors narrowing down which pods are considered, passed through to kubectl
+#[derive(Clone, Copy, Default)]
+pub struct PodSelectors<'a> {
+    /// Label selector, e.g. app=web,tier!=cache
+    pub labels: Option<&'a str>,
+    /// Field selector, e.g. spec.nodeName=ip-10-0-1-5
+    pub fields: Option<&'a str>,
+}
+
+impl<'a> PodSelectors<'a> {
+    /// Whether no selector is set
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_none() && self.fields.is_none()
+    }
+
+    /// kubectl arguments applying the selectors
+    pub fn args(&self) -> Vec<&'a str> {
+        let mut args = Vec::new();
+        if let Some(labels) = self.labels {
+            args.extend(["-l", labels]);
+        }
+        if let Some(fields) = self.fields {
+            args.extend(["--field-selector", fields]);
+        }
+        args
+    }
+
+    /// Human-readable description of a pattern combined with the selectors
+    pub fn describe(&self, pattern: Option<&str>) -> String {
+        let mut parts: Vec<String> = pattern.filter(|p| !p.is_empty()).map(str::to_string).into_iter().collect();
+        if let Some(labels) = self.labels {
+            parts.push(format!("-l {}", labels));
+        }
+        if let Some(fields) = self.fields {
+            parts.push(format!("--field-selector {}", fields));
+        }
+        parts.join(" ")
+    }
+}
+
+/// Display and filter options of the pods listing
+pub struct PodsOptions<'a> {
+    pub selectors: PodSelectors<'a>,
+    pub all_namespaces: bool,
+    pub wide: bool,
+    pub sort: Option<&'a str>,
+    pub statuses: &'a [String],
+    pub not_ready: bool,
+    pub watch: bool,
+    pub usage: bool,
+}
+
+impl PodsOptions<'_> {
+    /// Whether metrics-server usage is needed, to show it or to sort by it
+    pub fn needs_usage(&self) -> bool {
+        self.usage || matches!(self.sort, Some("cpu") | Some("memory"))
+    }
+
+    /// Optional columns of the table, for pods from one or several contexts
+    fn columns(&self, context: bool) -> PodColumns {
+        PodColumns { context, namespace: self.all_namespaces, wide: self.wide, usage: self.needs_usage(), ..Default::default() }
+    }
+}
 
 /// Handle the pods command - list all pods or filter by pattern
-pub fn handle_pods_command(pattern: Option<&str>, context_pattern: Option<&str>, namespace_pattern: Option<&str>) {
+/// With `contexts_pattern` pods are listed from every matching context at once
+pub fn handle_pods_command(
+    pattern: Option<&str>,
+    context_pattern: Option<&str>,
+    contexts_pattern: Option<&str>,
+    namespace_pattern: Option<&str>,
+    options: &PodsOptions,
+) {
+    if let Some(contexts_pattern) = contexts_pattern {
+        let contexts = context::resolve_contexts(contexts_pattern);
+        list_pods_across_contexts(pattern, &contexts, namespace_pattern, options);
+        return;
+    }
+    
     // Resolve context and namespace patterns
-    let resolved_context = context_pattern.and_then(|pattern| resolve_context_pattern(pattern));
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
     let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    protection::warn_if_protected(resolved_context.as_deref());
+    
+    if options.watch {
+        pods_watch::watch_pods(pattern, resolved_context.as_deref(), resolved_namespace.as_deref(), options);
+        return;
+    }
     
     match pattern {
         None => display::print_working("Listing pods..."),
         Some(p) => display::print_working(&format!("Listing pods matching pattern '{}'...", p)),
     }
-    list_pods(pattern, resolved_context.as_deref(), resolved_namespace.as_deref());
+    list_pods(pattern, resolved_context.as_deref(), resolved_namespace.as_deref(), options);
 }
 
 /// List pods in the specified context and namespace, optionally filtered by pattern
-pub fn list_pods(pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>) {
-    match kubectl::execute_with_context(&["get", "pods"], context, namespace) {
-        Ok(output) => {
-            display::print_pods_table(&output, pattern);
+pub fn list_pods(pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>, options: &PodsOptions) {
+    match get_pods(context, namespace, &options.selectors, options.all_namespaces) {
+        Ok(pods) => {
+            let extras = listing_extras(context, namespace, options);
+            let pods: Vec<(Option<&str>, &k8s::Pod)> = pods.iter().map(|pod| (None, pod)).collect();
+            let rows = listing_rows(pods, pattern, options, |_| Some(&extras));
+            display::print_pods_table(&rows, pattern, options.columns(false));
         }
         Err(error) => {
             display::print_error_and_exit(&format!("Error listing pods: {}", error));
@@ -26,15 +116,267 @@ pub fn list_pods(pattern: Option<&str>, context: Option<&str>, namespace: Option
     }
 }
 
-/// Find all pods matching a pattern
-pub fn find_pods(pattern: &str, context: Option<&str>, namespace: Option<&str>) -> Vec<String> {
+/// List pods from several contexts in parallel, in one table with a context column
+/// The namespace is used as an exact name, since patterns could resolve differently per context
+pub fn list_pods_across_contexts(pattern: Option<&str>, contexts: &[String], namespace: Option<&str>, options: &PodsOptions) {
+    for context in contexts {
+        protection::warn_if_protected(Some(context));
+    }
+    display::print_working(&format!("Listing pods in {} context(s)...", contexts.len()));
+    
+    type ContextPods = (Vec<k8s::Pod>, ListingExtras);
+    let outputs: Vec<(&String, Result<ContextPods, String>)> = thread::scope(|scope| {
+        let handles: Vec<_> = contexts
+            .iter()
+            .map(|context| scope.spawn(move || {
+                let pods = get_pods(Some(context), namespace, &options.selectors, options.all_namespaces)?;
+                Ok((pods, listing_extras(Some(context), namespace, options)))
+            }))
+            .collect();
+        contexts
+            .iter()
+            .zip(handles)
+            .map(|(context, handle)| (context, handle.join().unwrap_or_else(|_| Err("listing pods panicked".to_string()))))
+            .collect()
+    });
+    
+    let mut pods = Vec::new();
+    let mut extras: HashMap<&str, &ListingExtras> = HashMap::new();
+    for (context, output) in &outputs {
+        match output {
+            Ok((context_pods, context_extras)) => {
+                pods.extend(context_pods.iter().map(|pod| (Some(context.as_str()), pod)));
+                extras.insert(context.as_str(), context_extras);
+            }
+            Err(error) => display::print_warning(&format!("{}: {}", context, error.trim())),
+        }
+    }
+    let rows = listing_rows(pods, pattern, options, |context| context.and_then(|context| extras.get(context).copied()));
+    display::print_pods_table(&rows, pattern, options.columns(true));
+}
+
+/// What a pods listing shows besides the pods themselves, fetched per context
+#[derive(Default)]
+struct ListingExtras {
+    /// Current usage by "namespace/name", only fetched when the options need it
+    usage: HashMap<String, PodUsage>,
+    /// Pods with recent warning events, as "namespace/name"
+    warned: HashSet<String>,
+}
+
+/// Fetch usage and recent warnings for a listing
+fn listing_extras(context: Option<&str>, namespace: Option<&str>, options: &PodsOptions) -> ListingExtras {
+    let usage = if options.needs_usage() {
+        pod_usage::get_pod_usage_or_warn(context, namespace, &options.selectors, options.all_namespaces)
+    } else {
+        HashMap::new()
+    };
+    ListingExtras {
+        usage,
+        warned: events::pods_with_recent_warnings(context, namespace, options.all_namespaces),
+    }
+}
+
+/// Filter and sort pods like `select_pods`, then build their rows with usage and warning markers
+/// `extras_for` looks up the extras of a pod's context
+fn listing_rows<'a, 'b>(
+    pods: Vec<(Option<&'a str>, &k8s::Pod)>,
+    pattern: Option<&str>,
+    options: &PodsOptions,
+    extras_for: impl Fn(Option<&'a str>) -> Option<&'b ListingExtras>,
+) -> Vec<PodDisplay> {
+    let usage_for = |context: &Option<&'a str>, pod: &k8s::Pod| {
+        extras_for(*context).and_then(|extras| pod_usage::usage_of_pod(&extras.usage, pod)).copied()
+    };
+    let mut pods = select_pods(pods, pattern, options);
+    if let Some(resource @ ("cpu" | "memory")) = options.sort {
+        pod_usage::sort_by_usage(&mut pods, resource, usage_for);
+    }
+    pods.into_iter()
+        .map(|(context, pod)| {
+            let mut row = pod_row(pod, context);
+            if options.needs_usage() {
+                pod_usage::fill_usage_columns(&mut row, pod, usage_for(&context, pod).as_ref());
+            }
+            if extras_for(context).is_some_and(|extras| extras.warned.contains(&pod.metadata.key())) {
+                row.name = format!("{} ⚠", row.name);
+                row.warned = true;
+            }
+            row
+        })
+        .collect()
+}
+
+/// Get all pods of a namespace (or of every namespace) matching the selectors, with their full spec and status
+pub fn get_pods(context: Option<&str>, namespace: Option<&str>, selectors: &PodSelectors, all_namespaces: bool) -> Result<Vec<k8s::Pod>, String> {
+    let mut base_args = vec!["get", "pods", "-o", "json"];
+    base_args.extend(selectors.args());
+    if all_namespaces {
+        base_args.push("--all-namespaces");
+    }
+    let output = kubectl::execute_with_context(&base_args, context, namespace)?;
+    k8s::parse::<k8s::PodList>(&output, "pods").map(|list| list.items)
+}
+
+/// Filter and sort pods by the listing options, keeping whatever each pod is tagged with
+/// The pattern only matches pod names; globs like "api-*" must match the whole name
+pub fn select_pods<'a, T>(pods: Vec<(T, &'a k8s::Pod)>, pattern: Option<&str>, options: &PodsOptions) -> Vec<(T, &'a k8s::Pod)> {
+    let mut pods: Vec<(T, &k8s::Pod)> = pods
+        .into_iter()
+        .filter(|(_, pod)| pattern.is_none_or(|p| utils::matches_pattern(p, &pod.metadata.name)))
+        .filter(|(_, pod)| {
+            options.statuses.is_empty()
+                || options.statuses.iter().any(|status| status.eq_ignore_ascii_case(&pod.display_status()))
+        })
+        .filter(|(_, pod)| !options.not_ready || (!pod.is_ready() && pod.status.phase.as_deref() != Some("Succeeded")))
+        .collect();
+    
+    match options.sort {
+        Some("restarts") => pods.sort_by_key(|(_, pod)| std::cmp::Reverse(pod.restart_count())),
+        Some("age") => pods.sort_by_key(|(_, pod)| std::cmp::Reverse(created_seconds(pod))),
+        Some("name") => pods.sort_by(|(_, a), (_, b)| a.metadata.name.cmp(&b.metadata.name)),
+        Some("status") => pods.sort_by_key(|(_, pod)| pod.display_status()),
+        _ => {}
+    }
+    pods
+}
+
+/// Build the table row of a pod
+pub fn pod_row(pod: &k8s::Pod, context: Option<&str>) -> PodDisplay {
+    let (ready, total) = pod.ready_containers();
+    let age = match created_seconds(pod) {
+        Some(created) => time::format_duration(time::now_seconds() - created),
+        None => "-".to_string(),
+    };
+    PodDisplay {
+        context: context.unwrap_or_default().to_string(),
+        namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+        name: pod.metadata.name.clone(),
+        ready: format!("{}/{}", ready, total),
+        status: pod.display_status(),
+        restarts: pod.restart_count().to_string(),
+        age,
+        revision: "-".to_string(),
+        cpu: "-".to_string(),
+        cpu_requests: "-".to_string(),
+        memory: "-".to_string(),
+        memory_requests: "-".to_string(),
+        node: pod.spec.node_name.clone().unwrap_or_else(|| "<none>".to_string()),
+        ip: pod.status.pod_ip.clone().unwrap_or_else(|| "<none>".to_string()),
+        qos: pod.status.qos_class.clone().unwrap_or_else(|| "-".to_string()),
+        containers: pod.spec.containers.iter().map(|container| container.name.as_str()).collect::<Vec<_>>().join(","),
+        owner: pod.owner().unwrap_or_else(|| "-".to_string()),
+        cpu_hot: false,
+        memory_hot: false,
+        warned: false,
+    }
+}
+
+/// Creation time of a pod in seconds since the Unix epoch
+fn created_seconds(pod: &k8s::Pod) -> Option<i64> {
+    pod.metadata
+        .creation_timestamp
+        .as_deref()
+        .and_then(time::parse_rfc3339)
+        .map(|(seconds, _)| seconds)
+}
+
+/// Find all pods matching a pattern, always asking the cluster instead of the cache
+/// Destructive commands use this so they show exactly the pods that exist right now
+pub fn find_pods_fresh(pattern: &str, context: Option<&str>, namespace: Option<&str>) -> Vec<String> {
+    resolve_pod_names(pattern, context, namespace, true)
+}
+
+/// Pods of a namespace matching a pattern, from the cache unless `fresh` is set
+fn resolve_pod_names(pattern: &str, context: Option<&str>, namespace: Option<&str>, fresh: bool) -> Vec<String> {
     display::print_working(&format!("Resolving pods with pattern {}...", pattern));
-    match kubectl::execute_with_context(&["get", "pods", "-o", "name"], context, namespace) {
+    let fetch = || {
+        kubectl::execute_with_context(&["get", "pods", "-o", "name"], context, namespace).map(|output| {
+            output
+                .lines()
+                .map(|line| line.trim_start_matches("pod/").trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect()
+        })
+    };
+    let key = cache::pods_key(context, namespace);
+    let filter = |name: &str| utils::matches_pattern(pattern, name);
+    let result = if fresh {
+        cache::fresh_matching_names(&key, fetch, filter)
+    } else {
+        cache::matching_names(&key, fetch, filter)
+    };
+    result.unwrap_or_else(|error| {
+        display::print_error(&format!("Error finding pods: {}", error));
+        Vec::new()
+    })
+}
+
+/// Find pods matching a pattern and selectors in every namespace, as "namespace/name" entries
+/// Plain name lookups come from the cache while it is fresh
+fn find_pods_in_all_namespaces(pattern: &str, selectors: &PodSelectors, context: Option<&str>) -> Vec<String> {
+    display::print_working(&format!("Resolving pods with {} in all namespaces...", selectors.describe(Some(pattern))));
+    let mut base_args = vec![
+        "get", "pods", "--all-namespaces",
+        "-o", "jsonpath={range .items[*]}{.metadata.namespace}/{.metadata.name}{\"\\n\"}{end}",
+    ];
+    base_args.extend(selectors.args());
+    let fetch = || {
+        kubectl::execute_with_context(&base_args, context, None).map(|output| {
+            output
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|entry| !entry.is_empty())
+                .collect()
+        })
+    };
+    let name_matches = |entry: &str| entry.split_once('/').is_some_and(|(_, name)| utils::matches_pattern(pattern, name));
+    
+    // Selector results depend on the selectors, so only plain name lookups are cached
+    let key = cache::all_namespaces_pods_key(context);
+    let result = if selectors.is_empty() {
+        cache::matching_names(&key, fetch, name_matches)
+    } else {
+        fetch().map(|entries: Vec<String>| entries.into_iter().filter(|entry| name_matches(entry)).collect())
+    };
+    result.unwrap_or_else(|error| {
+        display::print_error(&format!("Error finding pods: {}", error));
+        Vec::new()
+    })
+}
+
+/// Check after a failed command whether the pod it ran against is gone - typically a name picked from stale cached names
+/// A gone pod's cached names are forgotten, so selecting again sees the pods that exist now
+pub fn is_gone(name: &str, context: Option<&str>, namespace: Option<&str>) -> bool {
+    let pod_ref = format!("pod/{}", name);
+    match kubectl::execute_with_context(&["get", &pod_ref, "-o", "name"], context, namespace) {
+        Err(error) if error.contains("NotFound") => {
+            display::print_warning(&format!("Pod {} no longer exists - refreshing pod names...", name));
+            forget_cached_pods(context, namespace);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Drop the cached pod names of a namespace, e.g. after a cached pod turned out to be gone
+/// The context's all-namespaces entries are dropped too, since the pod may have been picked from them
+pub fn forget_cached_pods(context: Option<&str>, namespace: Option<&str>) {
+    cache::invalidate(&cache::pods_key(context, namespace));
+    cache::invalidate(&cache::all_namespaces_pods_key(context));
+}
+
+/// Find all pods matching label and/or field selectors, optionally narrowed down by a name pattern
+pub fn find_pods_by_selectors(selectors: &PodSelectors, pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>) -> Vec<String> {
+    display::print_working(&format!("Resolving pods with {}...", selectors.describe(None)));
+    let mut base_args = vec!["get", "pods", "-o", "name"];
+    base_args.extend(selectors.args());
+    match kubectl::execute_with_context(&base_args, context, namespace) {
         Ok(output) => {
             output
                 .lines()
-                .filter(|line| line.contains(pattern))
                 .map(|line| line.trim_start_matches("pod/").trim().to_string())
+                .filter(|name| !name.is_empty() && pattern.is_none_or(|p| utils::matches_pattern(p, name)))
                 .collect()
         }
         Err(error) => {
@@ -44,9 +386,88 @@ pub fn find_pods(pattern: &str, context: Option<&str>, namespace: Option<&str>)
     }
 }
 
-/// Select a pod by pattern with user interaction if multiple matches
-pub fn select_pod(pattern: &str, context: Option<&str>, namespace: Option<&str>) -> Option<String> {
-    let matching_pods = find_pods(pattern, context, namespace);
-    utils::select_from_matches(matching_pods, pattern, "pod")
+/// Find the most recently created pod matching a name pattern and/or selectors,
+/// preferring running pods and optionally skipping one pod by name
+pub fn find_newest_pod(
+    pattern: Option<&str>,
+    selectors: &PodSelectors,
+    context: Option<&str>,
+    namespace: Option<&str>,
+    excluding: Option<&str>,
+) -> Option<String> {
+    let mut base_args = vec![
+        "get", "pods", "--sort-by=.metadata.creationTimestamp",
+        "-o", "jsonpath={range .items[*]}{.metadata.name} {.status.phase}{\"\\n\"}{end}",
+    ];
+    base_args.extend(selectors.args());
+    let output = kubectl::execute_with_context(&base_args, context, namespace).ok()?;
+
+    let candidates: Vec<(&str, &str)> = output
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .filter(|(name, _)| pattern.is_none_or(|p| utils::matches_pattern(p, name)) && Some(*name) != excluding)
+        .collect();
+
+    candidates
+        .iter()
+        .rfind(|(_, phase)| *phase == "Running")
+        .or(candidates.last())
+        .map(|(name, _)| name.to_string())
+}
+
+/// Get a single pod with its full status
+pub fn get_pod(name: &str, context: Option<&str>, namespace: Option<&str>) -> Result<k8s::Pod, String> {
+    let pod_ref = format!("pod/{}", name);
+    let output = kubectl::execute_with_context(&["get", &pod_ref, "-o", "json"], context, namespace)?;
+    k8s::parse(&output, "pod")
+}
+
+/// Check whether a pod still exists and is not being torn down
+pub fn is_pod_active(name: &str, context: Option<&str>, namespace: Option<&str>) -> bool {
+    let pod_ref = format!("pod/{}", name);
+    match kubectl::execute_with_context(
+        &["get", &pod_ref, "-o", "jsonpath={.status.phase} {.metadata.deletionTimestamp}"],
+        context,
+        namespace,
+    ) {
+        Ok(output) => {
+            let mut parts = output.split_whitespace();
+            let phase = parts.next().unwrap_or("");
+            let deleting = parts.next().is_some();
+            !deleting && phase != "Succeeded" && phase != "Failed"
+        }
+        Err(_) => false,
+    }
+}
+
+/// Select a pod like `select_pod`, searching every namespace when `all_namespaces` is set
+/// Returns the pod name with the namespace to use for it - the picked pod's own namespace in all-namespaces mode
+pub fn select_pod_with_namespace(
+    pattern: &str,
+    selectors: &PodSelectors,
+    context: Option<&str>,
+    namespace: Option<&str>,
+    all_namespaces: bool,
+) -> Option<(String, Option<String>)> {
+    if !all_namespaces {
+        return select_pod(pattern, selectors, context, namespace).map(|name| (name, namespace.map(str::to_string)));
+    }
+    
+    let matching_pods = find_pods_in_all_namespaces(pattern, selectors, context);
+    let entry = utils::select_from_matches(matching_pods, &selectors.describe(Some(pattern)), "pod")?;
+    let (namespace, name) = entry.split_once('/')?;
+    Some((name.to_string(), Some(namespace.to_string())))
+}
+
+/// Select a pod by pattern and selectors with user interaction if multiple matches
+/// Names may come from the cache - callers use `is_gone` when the pod fails them, and select again
+pub fn select_pod(pattern: &str, selectors: &PodSelectors, context: Option<&str>, namespace: Option<&str>) -> Option<String> {
+    let description = selectors.describe(Some(pattern));
+    if !selectors.is_empty() {
+        let matching_pods = find_pods_by_selectors(selectors, Some(pattern), context, namespace);
+        return utils::select_from_matches(matching_pods, &description, "pod");
+    }
+
+    let matching_pods = resolve_pod_names(pattern, context, namespace, false);
+    utils::select_from_matches(matching_pods, &description, "pod")
 }
- 
\ No newline at end of file
@@ -0,0 +1,269 @@
+use crate::{kubectl, display, k8s, time};
+use crate::commands::pods::{self, PodsOptions};
+use crate::display::{PodChange, PodColumns, PodDisplay};
+use std::collections::{BTreeMap, HashSet};
+use std::io::{BufReader, Read};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a change stays highlighted (and a removed pod stays listed)
+const HIGHLIGHT_DURATION: Duration = Duration::from_secs(10);
+
+/// Events arriving this close together are drawn in a single refresh
+const REDRAW_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Delay before reopening a watch stream the API server closed
+const RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// Messages from the thread reading the kubectl watch stream
+enum WatchMessage {
+    Event(Box<k8s::WatchEvent<k8s::Pod>>),
+    /// The stream ended, with kubectl's error output if it failed
+    Ended(Option<String>),
+}
+
+/// A pod on the dashboard and its latest change
+struct WatchedPod {
+    pod: k8s::Pod,
+    change: Option<(PodChange, Instant)>,
+}
+
+/// Show a live pods dashboard that redraws on every change until interrupted
+/// The initial listing is the baseline, so only later changes are highlighted
+pub fn watch_pods(pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>, options: &PodsOptions) {
+    let initial = match pods::get_pods(context, namespace, &options.selectors, options.all_namespaces) {
+        Ok(initial) => initial,
+        Err(error) => display::print_error_and_exit(&format!("Error listing pods: {}", error)),
+    };
+    let mut watched: BTreeMap<String, WatchedPod> = initial
+        .into_iter()
+        .map(|pod| (pod.metadata.key(), WatchedPod { pod, change: None }))
+        .collect();
+
+    let title = dashboard_title(pattern, context, namespace, options.all_namespaces);
+    let (sender, receiver) = mpsc::channel();
+    start_watch_stream(context, namespace, options, sender.clone());
+    draw(&title, &watched, pattern, options, None);
+
+    let mut received_events = false;
+    // Why the last stream failed, shown until events flow again
+    let mut stream_error: Option<String> = None;
+    loop {
+        let dirty = match receiver.recv_timeout(Duration::from_secs(1)) {
+            Ok(WatchMessage::Event(event)) => {
+                received_events = true;
+                stream_error = None;
+                apply_event(&mut watched, *event);
+                drain_pending(&receiver, &sender, &mut watched);
+                true
+            }
+            Ok(WatchMessage::Ended(error)) => {
+                if let Some(error) = &error
+                    && !received_events {
+                    display::print_error_and_exit(&format!("Error watching pods: {}", error.trim()));
+                }
+                stream_error = error.map(|error| error.trim().to_string());
+                if stream_error.is_some() {
+                    draw(&title, &watched, pattern, options, stream_error.as_deref());
+                }
+                // The API server closes watches after a while - reopen; the replayed state only highlights real changes
+                thread::sleep(RECONNECT_DELAY);
+                let removed = mark_missing_pods(&mut watched, context, namespace, options);
+                start_watch_stream(context, namespace, options, sender.clone());
+                removed
+            }
+            Err(RecvTimeoutError::Timeout) => expire_changes(&mut watched),
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+
+        if dirty {
+            draw(&title, &watched, pattern, options, stream_error.as_deref());
+        }
+    }
+}
+
+/// Mark pods that disappeared while no stream was open as removed - a new stream only replays pods that still exist
+/// Returns whether any pod was marked
+fn mark_missing_pods(watched: &mut BTreeMap<String, WatchedPod>, context: Option<&str>, namespace: Option<&str>, options: &PodsOptions) -> bool {
+    let Ok(current) = pods::get_pods(context, namespace, &options.selectors, options.all_namespaces) else {
+        // The new stream will most likely fail too and report why
+        return false;
+    };
+    let existing: HashSet<String> = current.iter().map(|pod| pod.metadata.key()).collect();
+
+    let mut removed = false;
+    for (key, pod) in watched.iter_mut() {
+        if !existing.contains(key) && !pod.change.is_some_and(|(change, _)| change == PodChange::Removed) {
+            pod.change = Some((PodChange::Removed, Instant::now()));
+            removed = true;
+        }
+    }
+    removed
+}
+
+/// Apply events that arrive in quick succession, so a burst causes a single redraw
+/// The end of a stream is put back for the main loop to handle
+fn drain_pending(receiver: &Receiver<WatchMessage>, sender: &Sender<WatchMessage>, watched: &mut BTreeMap<String, WatchedPod>) {
+    let deadline = Instant::now() + REDRAW_DEBOUNCE;
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        match receiver.recv_timeout(remaining) {
+            Ok(WatchMessage::Event(event)) => apply_event(watched, *event),
+            Ok(ended) => {
+                let _ = sender.send(ended);
+                return;
+            }
+            Err(_) => return,
+        }
+    }
+}
+
+/// Start `kubectl get pods --watch` in the background, forwarding each event
+fn start_watch_stream(context: Option<&str>, namespace: Option<&str>, options: &PodsOptions, sender: Sender<WatchMessage>) {
+    let mut base_args = vec!["get", "pods", "--watch", "--output-watch-events", "-o", "json"];
+    base_args.extend(options.selectors.args());
+    if options.all_namespaces {
+        base_args.push("--all-namespaces");
+    }
+    let kubectl_args = kubectl::build_args(&base_args, context, namespace);
+
+    let mut child = Command::new("kubectl")
+        .args(&kubectl_args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|_| display::print_error_and_exit("Failed to start kubectl process"));
+
+    // Drain stderr concurrently so kubectl never blocks on a full stderr pipe during a long watch
+    let stderr = child.stderr.take();
+    let stderr_reader = thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = stderr {
+            let _ = pipe.read_to_string(&mut output);
+        }
+        output
+    });
+
+    thread::spawn(move || {
+        if let Some(stdout) = child.stdout.take() {
+            // Events are concatenated JSON documents
+            let events = serde_json::Deserializer::from_reader(BufReader::new(stdout))
+                .into_iter::<k8s::WatchEvent<k8s::Pod>>();
+            for event in events {
+                let Ok(event) = event else { break };
+                if sender.send(WatchMessage::Event(Box::new(event))).is_err() {
+                    // Nobody listens anymore - stop kubectl instead of leaving it running
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return;
+                }
+            }
+        }
+
+        let status = child.wait();
+        let stderr = stderr_reader.join().unwrap_or_default();
+        let error = match status {
+            Ok(status) if status.success() => None,
+            Ok(_) => Some(stderr),
+            Err(e) => Some(e.to_string()),
+        };
+        let _ = sender.send(WatchMessage::Ended(error));
+    });
+}
+
+/// Update the dashboard state with a watch event, remembering what changed
+fn apply_event(watched: &mut BTreeMap<String, WatchedPod>, event: k8s::WatchEvent<k8s::Pod>) {
+    if event.object.metadata.name.is_empty() {
+        return;
+    }
+    let name = event.object.metadata.key();
+
+    match event.event_type.as_str() {
+        "ADDED" | "MODIFIED" => {
+            match watched.get_mut(&name) {
+                Some(existing) => {
+                    let change = if existing.change.is_some_and(|(change, _)| change == PodChange::Removed) {
+                        Some(PodChange::New)
+                    } else if event.object.restart_count() > existing.pod.restart_count() {
+                        Some(PodChange::Restarted)
+                    } else if event.object.display_status() != existing.pod.display_status() {
+                        Some(PodChange::StatusChanged)
+                    } else {
+                        None
+                    };
+                    if let Some(change) = change {
+                        existing.change = Some((change, Instant::now()));
+                    }
+                    existing.pod = event.object;
+                }
+                None => {
+                    watched.insert(name, WatchedPod {
+                        pod: event.object,
+                        change: Some((PodChange::New, Instant::now())),
+                    });
+                }
+            }
+        }
+        "DELETED" => {
+            if let Some(existing) = watched.get_mut(&name) {
+                existing.change = Some((PodChange::Removed, Instant::now()));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Drop highlights that are old enough, and removed pods with them
+/// Returns whether anything changed on screen
+fn expire_changes(watched: &mut BTreeMap<String, WatchedPod>) -> bool {
+    let before = watched.len();
+    watched.retain(|_, pod| {
+        !pod.change.is_some_and(|(change, at)| change == PodChange::Removed && at.elapsed() >= HIGHLIGHT_DURATION)
+    });
+
+    let mut expired = watched.len() != before;
+    for pod in watched.values_mut() {
+        if pod.change.is_some_and(|(_, at)| at.elapsed() >= HIGHLIGHT_DURATION) {
+            pod.change = None;
+            expired = true;
+        }
+    }
+    expired
+}
+
+/// Redraw the dashboard with the pods that pass the listing filters, and why the watch is reconnecting if it failed
+fn draw(title: &str, watched: &BTreeMap<String, WatchedPod>, pattern: Option<&str>, options: &PodsOptions, stream_error: Option<&str>) {
+    let pods: Vec<(Option<PodChange>, &k8s::Pod)> = watched
+        .values()
+        .map(|watched| (watched.change.map(|(change, _)| change), &watched.pod))
+        .collect();
+    let (changes, rows): (Vec<Option<PodChange>>, Vec<PodDisplay>) = pods::select_pods(pods, pattern, options)
+        .into_iter()
+        .map(|(change, pod)| (change, pods::pod_row(pod, None)))
+        .unzip();
+
+    let mut title = format!("{} - updated {} (Ctrl+C to stop)", title, time::now_rfc3339());
+    if let Some(error) = stream_error {
+        title.push_str(&format!("\n⚠ Watch failed, reconnecting every {}s: {}", RECONNECT_DELAY.as_secs(), error));
+    }
+    let columns = PodColumns { namespace: options.all_namespaces, wide: options.wide, ..Default::default() };
+    display::print_pods_dashboard(&title, &rows, &changes, columns);
+}
+
+/// Title line describing what is being watched
+fn dashboard_title(pattern: Option<&str>, context: Option<&str>, namespace: Option<&str>, all_namespaces: bool) -> String {
+    let mut title = "👀 Watching pods".to_string();
+    if let Some(pattern) = pattern {
+        title.push_str(&format!(" matching '{}'", pattern));
+    }
+    if let Some(context) = context {
+        title.push_str(&format!(" in {}", context));
+    }
+    if all_namespaces {
+        title.push_str(" (all namespaces)");
+    } else if let Some(namespace) = namespace {
+        title.push_str(&format!(" ({})", namespace));
+    }
+    title
+}
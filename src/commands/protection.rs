@@ -0,0 +1,68 @@
+use crate::{audit, display, utils};
+use crate::audit::AuditEntry;
+use crate::commands::config::KubixConfig;
+use crate::commands::context::get_current_context;
+
+/// The context a command really runs against: the resolved one, or the current context
+pub fn effective_context(context: Option<&str>) -> Option<String> {
+    context.map(str::to_string).or_else(get_current_context)
+}
+
+/// Where an action will run, for confirmation messages (e.g. " in prod (payments)")
+pub fn describe_target(context: Option<&str>, namespace: Option<&str>) -> String {
+    match (effective_context(context), namespace) {
+        (Some(context), Some(namespace)) => format!(" in {} ({})", context, namespace),
+        (Some(context), None) => format!(" in {}", context),
+        (None, Some(namespace)) => format!(" in namespace {}", namespace),
+        (None, None) => String::new(),
+    }
+}
+
+/// Whether a context matches one of the protected context patterns
+pub fn is_protected(context: &str) -> bool {
+    KubixConfig::load().is_protected_context(context)
+}
+
+/// Show the protected-context banner when the command targets a protected context
+pub fn warn_if_protected(context: Option<&str>) {
+    if KubixConfig::load().protected_contexts.is_empty() {
+        return;
+    }
+    if let Some(context) = effective_context(context)
+        && is_protected(&context) {
+        display::print_protected_banner(&context);
+    }
+}
+
+/// Guard a mutating action: on a protected context the user has to type the context name
+/// The banner is left to `warn_if_protected`, which commands call as they start
+/// Returns false if the user did not confirm
+pub fn confirm_mutating_action(entry: &AuditEntry) -> bool {
+    let Some(context) = effective_context(entry.context.as_deref()) else {
+        return true;
+    };
+    if !is_protected(&context) {
+        return true;
+    }
+
+    display::print_warning(&format!("About to {} on protected context {}", describe(entry), context));
+    utils::prompt_for_typed_confirmation(&format!("Type the context name '{}' to confirm:", context), &context)
+}
+
+/// Guard a mutating action and exit when it is not confirmed, recording the declined action
+pub fn confirm_mutating_action_or_exit(entry: &AuditEntry) {
+    if !confirm_mutating_action(entry) {
+        audit::record(entry.clone().cancelled());
+        display::print_error_and_exit("Operation cancelled - context name did not match.");
+    }
+}
+
+/// Human readable description of an audited action
+fn describe(entry: &AuditEntry) -> String {
+    match (&entry.pod, &entry.command) {
+        (Some(pod), Some(command)) => format!("{} '{}' in pod {}", entry.action, command, pod),
+        (Some(pod), None) => format!("{} pod {}", entry.action, pod),
+        (None, Some(command)) => format!("{} '{}'", entry.action, command),
+        (None, None) => entry.action.clone(),
+    }
+}
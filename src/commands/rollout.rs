@@ -0,0 +1,316 @@
+use crate::{kubectl, display, k8s, time, utils, audit};
+use crate::audit::AuditEntry;
+use crate::commands::{pods, protection, workloads, resolve_context_pattern, resolve_namespace_pattern};
+use crate::commands::pods::PodSelectors;
+use crate::commands::workloads::{Workload, WorkloadKind};
+use crate::display::{PodColumns, RevisionDisplay};
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+/// How often the rollout progress view refreshes
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Annotation recording why a revision was made (set by `kubectl annotate` or CI)
+const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+
+/// Where a deployment rollout stands, by the same rules as `kubectl rollout status`
+enum RolloutState {
+    /// The controller has not picked up the latest spec yet
+    Pending,
+    /// Pods are being replaced, with what is still missing
+    Progressing(String),
+    Complete,
+    /// The rollout exceeded its progress deadline
+    Failed(String),
+}
+
+/// Handle the restart command - rollout restart one or more workloads picked by pattern
+/// Plain patterns match deployments; "sts/db" or "ds/agent" pick other kinds
+pub fn handle_restart_command(target: &str, context_pattern: Option<&str>, namespace_pattern: Option<&str>, dry_run: bool) {
+    let (kind, pattern) = workloads::parse_workload_target(target).unwrap_or((WorkloadKind::Deployment, target));
+    if !matches!(kind, WorkloadKind::Deployment | WorkloadKind::StatefulSet | WorkloadKind::DaemonSet) {
+        display::print_error_and_exit(&format!("Only deployments, statefulsets and daemonsets can be restarted, not {}s", kind.resource()));
+    }
+
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    let (context, namespace) = (resolved_context.as_deref(), resolved_namespace.as_deref());
+    protection::warn_if_protected(context);
+
+    let matches = workloads::find_workloads(kind, pattern, context, namespace);
+    let Some(selected) = utils::select_multiple_from_matches(matches, pattern, kind.resource()) else {
+        display::print_error_and_exit("Operation cancelled.");
+    };
+
+    let resources: Vec<String> = selected.into_iter().map(|name| Workload { kind, name }.to_string()).collect();
+    let location = protection::describe_target(context, namespace);
+    display::print_affected_resources(
+        &format!("🔄 {} workload(s) will be restarted{} - their pods are replaced one by one:", resources.len(), location),
+        &resources,
+    );
+    if dry_run {
+        display::print_info("Dry run - nothing was restarted.");
+        return;
+    }
+
+    let entry = AuditEntry::new("restart", context, namespace).command(&resources.join(","));
+    protection::confirm_mutating_action_or_exit(&entry);
+    if !utils::prompt_for_confirmation(&format!("Restart {} workload(s)?", resources.len())) {
+        display::print_error_and_exit("Operation cancelled.");
+    }
+
+    let mut args = vec!["rollout", "restart"];
+    args.extend(resources.iter().map(String::as_str));
+    let result = kubectl::execute_with_context(&args, context, namespace);
+    audit::record(entry.exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+
+    match result {
+        Ok(output) => {
+            for line in output.lines().filter(|line| !line.trim().is_empty()) {
+                display::print_success(line.trim());
+            }
+        }
+        Err(error) => display::print_error_and_exit(&format!("Error restarting workloads: {}", error.trim())),
+    }
+}
+
+/// Handle the rollout command - follow the rollout of a deployment, or show its revisions
+pub fn handle_rollout_command(pattern: &str, context_pattern: Option<&str>, namespace_pattern: Option<&str>, history: bool) {
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    let (context, namespace) = (resolved_context.as_deref(), resolved_namespace.as_deref());
+    protection::warn_if_protected(context);
+
+    let workload = select_deployment(pattern, context, namespace);
+    if history {
+        show_history(&workload, context, namespace);
+    } else {
+        follow_rollout(&workload, context, namespace);
+    }
+}
+
+/// Handle the rollout undo command - roll a deployment back to an earlier revision, then follow the rollout
+pub fn handle_rollout_undo_command(
+    pattern: &str,
+    to_revision: Option<u64>,
+    context_pattern: Option<&str>,
+    namespace_pattern: Option<&str>,
+    dry_run: bool,
+) {
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    let (context, namespace) = (resolved_context.as_deref(), resolved_namespace.as_deref());
+    protection::warn_if_protected(context);
+
+    let workload = select_deployment(pattern, context, namespace);
+    let (deployment, replica_sets) = load_deployment(&workload, context, namespace);
+    let current = deployment.revision();
+    let find_revision = |revision: u64| replica_sets.iter().find(|replica_set| replica_set.revision() == Some(revision));
+
+    let target = match to_revision {
+        Some(revision) if Some(revision) == current => {
+            display::print_error_and_exit(&format!("{} is already at revision {}", workload, revision));
+        }
+        Some(revision) => find_revision(revision)
+            .unwrap_or_else(|| display::print_error_and_exit(&format!("Revision {} of {} not found - see `kubix rollout {} --history`", revision, workload, workload.name))),
+        None => replica_sets
+            .iter()
+            .filter(|replica_set| replica_set.revision() < current)
+            .max_by_key(|replica_set| replica_set.revision())
+            .unwrap_or_else(|| display::print_error_and_exit(&format!("{} has no previous revision to roll back to", workload))),
+    };
+    let target_revision = target.revision().unwrap_or_default();
+
+    let mut changes = Vec::new();
+    if let Some(current) = current {
+        changes.push(format!("from revision {}: {}", current, template_images(&deployment.spec)));
+    }
+    changes.push(format!("to revision {}: {}", target_revision, template_images(&target.spec)));
+    let location = protection::describe_target(context, namespace);
+    display::print_affected_resources(&format!("⏪ {} will be rolled back{}:", workload, location), &changes);
+    if dry_run {
+        display::print_info("Dry run - nothing was rolled back.");
+        return;
+    }
+
+    let revision_arg = format!("--to-revision={}", target_revision);
+    let resource = workload.to_string();
+    let entry = AuditEntry::new("rollout undo", context, namespace).command(&format!("{} {}", resource, revision_arg));
+    protection::confirm_mutating_action_or_exit(&entry);
+    if !utils::prompt_for_confirmation(&format!("Roll back {} to revision {}?", workload, target_revision)) {
+        display::print_error_and_exit("Operation cancelled.");
+    }
+
+    let result = kubectl::execute_with_context(&["rollout", "undo", &resource, &revision_arg], context, namespace);
+    audit::record(entry.exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+    match result {
+        Ok(output) => display::print_success(output.trim()),
+        Err(error) => display::print_error_and_exit(&format!("Error rolling back {}: {}", workload, error.trim())),
+    }
+    follow_rollout(&workload, context, namespace);
+}
+
+/// Select a deployment by pattern, exiting if none matches
+fn select_deployment(pattern: &str, context: Option<&str>, namespace: Option<&str>) -> Workload {
+    workloads::select_workload(WorkloadKind::Deployment, pattern, context, namespace)
+        .unwrap_or_else(|| display::print_error_and_exit(&format!("No deployment found matching pattern: {}", pattern)))
+}
+
+/// Get a deployment with its replica sets (one per revision, oldest first), exiting on errors
+fn load_deployment(workload: &Workload, context: Option<&str>, namespace: Option<&str>) -> (k8s::Deployment, Vec<k8s::ReplicaSet>) {
+    let selector = workloads::get_pod_selector(workload, context, namespace)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error resolving pods of {}: {}", workload, error)));
+    let deployment = workloads::get_workload(workload, context, namespace)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error getting {}: {}", workload, error.trim())));
+    let replica_sets = get_replica_sets(&workload.name, &selector, context, namespace)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error getting revisions of {}: {}", workload, error.trim())));
+    (deployment, replica_sets)
+}
+
+/// Get the replica sets of a deployment, oldest revision first
+/// Replica sets carry the deployment's pod labels, so its selector finds them; ownership rules out look-alikes
+fn get_replica_sets(deployment: &str, selector: &str, context: Option<&str>, namespace: Option<&str>) -> Result<Vec<k8s::ReplicaSet>, String> {
+    let output = kubectl::execute_with_context(&["get", "replicasets", "-l", selector, "-o", "json"], context, namespace)?;
+    let mut replica_sets: Vec<k8s::ReplicaSet> = k8s::parse::<k8s::ReplicaSetList>(&output, "replica sets")?
+        .items
+        .into_iter()
+        .filter(|replica_set| replica_set.is_owned_by(deployment))
+        .collect();
+    replica_sets.sort_by_key(|replica_set| replica_set.revision());
+    Ok(replica_sets)
+}
+
+/// Show the revisions of a deployment with their images and change causes
+fn show_history(workload: &Workload, context: Option<&str>, namespace: Option<&str>) {
+    let (deployment, replica_sets) = load_deployment(workload, context, namespace);
+    let current = deployment.revision();
+
+    let rows: Vec<RevisionDisplay> = replica_sets
+        .iter()
+        .map(|replica_set| RevisionDisplay {
+            revision: replica_set.revision().map(|revision| revision.to_string()).unwrap_or_else(|| "?".to_string()),
+            created: replica_set.metadata.creation_timestamp.as_deref().map(time::format_ago).unwrap_or_else(|| "-".to_string()),
+            pods: format!("{}/{}", replica_set.status.ready_replicas, replica_set.spec.replicas.unwrap_or_default()),
+            images: template_images(&replica_set.spec),
+            change_cause: replica_set.metadata.annotations.get(CHANGE_CAUSE_ANNOTATION).cloned().unwrap_or_else(|| "-".to_string()),
+            current: replica_set.revision().is_some() && replica_set.revision() == current,
+        })
+        .collect();
+
+    let location = protection::describe_target(context, namespace);
+    display::print_revisions_table(&format!("📜 Revisions of {}{}:", workload, location), &rows);
+}
+
+/// Redraw the progress of a deployment rollout until it completes or fails
+fn follow_rollout(workload: &Workload, context: Option<&str>, namespace: Option<&str>) {
+    let selector = workloads::get_pod_selector(workload, context, namespace)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error resolving pods of {}: {}", workload, error)));
+    let selectors = PodSelectors { labels: Some(&selector), fields: None };
+    let title = format!("🚀 Rollout of {}{}", workload, protection::describe_target(context, namespace));
+
+    loop {
+        let deployment = workloads::get_workload(workload, context, namespace)
+            .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error getting {}: {}", workload, error.trim())));
+        // Revisions only label the pods table, so a failed lookup is not worth stopping for
+        let replica_sets = get_replica_sets(&workload.name, &selector, context, namespace).unwrap_or_default();
+        let pods = pods::get_pods(context, namespace, &selectors, false)
+            .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error listing pods of {}: {}", workload, error.trim())));
+
+        let state = rollout_state(&deployment);
+        draw(&title, &deployment, &replica_sets, &pods, &state);
+        match state {
+            RolloutState::Complete => {
+                let revision = deployment.revision().map(|revision| format!(" (revision {})", revision)).unwrap_or_default();
+                display::print_success(&format!("{} successfully rolled out{}", workload, revision));
+                return;
+            }
+            RolloutState::Failed(reason) => display::print_error_and_exit(&format!("Rollout of {} failed: {}", workload, reason)),
+            RolloutState::Pending | RolloutState::Progressing(_) => thread::sleep(REFRESH_INTERVAL),
+        }
+    }
+}
+
+/// Where the rollout of a deployment stands
+fn rollout_state(deployment: &k8s::Deployment) -> RolloutState {
+    let status = &deployment.status;
+    if deployment.metadata.generation.is_some_and(|generation| status.observed_generation.is_none_or(|observed| observed < generation)) {
+        return RolloutState::Pending;
+    }
+    if let Some(progressing) = deployment.condition("Progressing")
+        && progressing.reason.as_deref() == Some("ProgressDeadlineExceeded") {
+        return RolloutState::Failed(progressing.message.clone().unwrap_or_else(|| "progress deadline exceeded".to_string()));
+    }
+
+    let desired = deployment.desired_replicas();
+    if status.updated_replicas < desired {
+        RolloutState::Progressing(format!("{} of {} new replicas updated", status.updated_replicas, desired))
+    } else if status.replicas > status.updated_replicas {
+        RolloutState::Progressing(format!("{} old replica(s) pending termination", status.replicas - status.updated_replicas))
+    } else if status.available_replicas < status.updated_replicas {
+        RolloutState::Progressing(format!("{} of {} updated replicas available", status.available_replicas, status.updated_replicas))
+    } else {
+        RolloutState::Complete
+    }
+}
+
+/// Draw one refresh of the rollout progress view
+fn draw(title: &str, deployment: &k8s::Deployment, replica_sets: &[k8s::ReplicaSet], pods: &[k8s::Pod], state: &RolloutState) {
+    let current = deployment.revision();
+    let revisions: HashMap<&str, u64> = replica_sets
+        .iter()
+        .filter_map(|replica_set| replica_set.revision().map(|revision| (replica_set.metadata.name.as_str(), revision)))
+        .collect();
+
+    let mut sorted: Vec<&k8s::Pod> = pods.iter().collect();
+    sorted.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+    let rows: Vec<_> = sorted
+        .into_iter()
+        .map(|pod| {
+            let mut row = pods::pod_row(pod, None);
+            let revision = pod
+                .metadata
+                .owner_references
+                .iter()
+                .find(|owner| owner.kind == "ReplicaSet")
+                .and_then(|owner| revisions.get(owner.name.as_str()));
+            if let Some(&revision) = revision {
+                row.revision = if Some(revision) == current { format!("{} (new)", revision) } else { revision.to_string() };
+            }
+            row
+        })
+        .collect();
+
+    let status = &deployment.status;
+    let unavailable = format!("Unavailable: {}", status.unavailable_replicas);
+    let replicas = format!(
+        "Desired: {}  Updated: {}  Ready: {}  Available: {}  {}",
+        deployment.desired_replicas(),
+        status.updated_replicas,
+        status.ready_replicas,
+        status.available_replicas,
+        if status.unavailable_replicas > 0 { unavailable.red().to_string() } else { unavailable }
+    );
+    let state = match state {
+        RolloutState::Pending => "⏳ Waiting for the deployment controller to pick up the change...".yellow().to_string(),
+        RolloutState::Progressing(progress) => format!("⏳ Rolling out: {}...", progress).yellow().to_string(),
+        RolloutState::Complete => "✅ Rollout complete".green().to_string(),
+        RolloutState::Failed(reason) => format!("❌ Rollout failed: {}", reason).red().to_string(),
+    };
+
+    let revision = current.map(|revision| format!(" - revision {}", revision)).unwrap_or_default();
+    let title = format!("{}{} - updated {} (Ctrl+C to stop)", title, revision, time::now_rfc3339());
+    display::print_workload_dashboard(&title, &replicas, &state, &rows, PodColumns { revision: true, ..Default::default() });
+}
+
+/// Container images of a pod template, as "container=image"
+fn template_images(spec: &k8s::DeploymentSpec) -> String {
+    spec.template
+        .spec
+        .containers
+        .iter()
+        .map(|container| format!("{}={}", container.name, container.image))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
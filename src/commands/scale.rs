@@ -0,0 +1,154 @@
+use crate::{kubectl, display, k8s, time, utils, audit};
+use crate::audit::AuditEntry;
+use crate::commands::{pods, protection, workloads, resolve_context_pattern, resolve_namespace_pattern};
+use crate::commands::pods::PodSelectors;
+use crate::commands::workloads::{Workload, WorkloadKind};
+use crate::display::PodColumns;
+use owo_colors::OwoColorize;
+use std::thread;
+use std::time::Duration;
+
+/// How often the progress view of --wait refreshes
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A requested replica count: absolute ("3") or relative to the current one ("+2", "-1")
+#[derive(Clone, Copy)]
+enum ReplicaChange {
+    Absolute(u32),
+    Relative(i64),
+}
+
+impl ReplicaChange {
+    /// Parse "3", "+2" or "-1"
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.starts_with(['+', '-']) {
+            value.parse().ok().map(Self::Relative)
+        } else {
+            value.parse().ok().map(Self::Absolute)
+        }
+    }
+
+    /// Replica count after applying the change, None if it would go below zero
+    fn apply(self, current: u32) -> Option<u32> {
+        match self {
+            Self::Absolute(replicas) => Some(replicas),
+            Self::Relative(delta) => u32::try_from(i64::from(current) + delta).ok(),
+        }
+    }
+}
+
+/// Handle the scale command - set the replica count of a deployment or statefulset, optionally waiting for it
+pub fn handle_scale_command(
+    target: &str,
+    replicas: &str,
+    context_pattern: Option<&str>,
+    namespace_pattern: Option<&str>,
+    wait: bool,
+    dry_run: bool,
+) {
+    let (kind, pattern) = workloads::parse_workload_target(target).unwrap_or((WorkloadKind::Deployment, target));
+    if !matches!(kind, WorkloadKind::Deployment | WorkloadKind::StatefulSet) {
+        display::print_error_and_exit(&format!("Only deployments and statefulsets can be scaled, not {}s", kind.resource()));
+    }
+    let Some(change) = ReplicaChange::parse(replicas) else {
+        display::print_error_and_exit(&format!("Invalid replica count '{}' - use a number such as 3, or a change such as +2 or -1", replicas));
+    };
+
+    let resolved_context = context_pattern.and_then(resolve_context_pattern);
+    let resolved_namespace = namespace_pattern.and_then(|pattern| resolve_namespace_pattern(pattern, resolved_context.as_deref()));
+    let (context, namespace) = (resolved_context.as_deref(), resolved_namespace.as_deref());
+    protection::warn_if_protected(context);
+
+    let workload = workloads::select_workload(kind, pattern, context, namespace)
+        .unwrap_or_else(|| display::print_error_and_exit(&format!("No {} found matching pattern: {}", kind.resource(), pattern)));
+    let current = workloads::get_workload(&workload, context, namespace)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error getting {}: {}", workload, error.trim())));
+    let current_replicas = current.desired_replicas();
+    let Some(desired) = change.apply(current_replicas) else {
+        display::print_error_and_exit(&format!("Cannot scale {} below 0 replicas (it has {})", workload, current_replicas));
+    };
+    if desired == current_replicas {
+        display::print_info(&format!("{} already has {} replica(s) - nothing to do.", workload, desired));
+        return;
+    }
+
+    let location = protection::describe_target(context, namespace);
+    display::print_affected_resources(
+        &format!("📏 {} will be scaled{}:", workload, location),
+        &[format!("replicas: {} → {} ({} ready now)", current_replicas, desired, current.status.ready_replicas)],
+    );
+    if desired == 0 {
+        display::print_warning(&format!("Scaling to 0 stops every pod of {}", workload));
+    }
+    if dry_run {
+        display::print_info("Dry run - nothing was scaled.");
+        return;
+    }
+
+    let resource = workload.to_string();
+    let replicas_arg = format!("--replicas={}", desired);
+    let entry = AuditEntry::new("scale", context, namespace).command(&format!("{} {}", resource, replicas_arg));
+    protection::confirm_mutating_action_or_exit(&entry);
+    if !utils::prompt_for_confirmation(&format!("Scale {} from {} to {} replicas?", workload, current_replicas, desired)) {
+        display::print_error_and_exit("Operation cancelled.");
+    }
+
+    // Refuse to scale if someone else changed the replica count since we read it
+    let current_arg = format!("--current-replicas={}", current_replicas);
+    let result = kubectl::execute_with_context(&["scale", &resource, &replicas_arg, &current_arg], context, namespace);
+    pods::forget_cached_pods(context, namespace);
+    audit::record(entry.exit_code(Some(if result.is_ok() { 0 } else { 1 })));
+    match result {
+        Ok(output) => display::print_success(output.trim()),
+        Err(error) => display::print_error_and_exit(&format!("Error scaling {}: {}", workload, error.trim())),
+    }
+
+    if wait {
+        wait_for_replicas(&workload, desired, context, namespace);
+    }
+}
+
+/// Redraw the workload's pods until it runs the desired number of ready replicas
+fn wait_for_replicas(workload: &Workload, desired: u32, context: Option<&str>, namespace: Option<&str>) {
+    let selector = workloads::get_pod_selector(workload, context, namespace)
+        .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error resolving pods of {}: {}", workload, error)));
+    let selectors = PodSelectors { labels: Some(&selector), fields: None };
+    let title = format!("📏 Scaling {} to {} replica(s){}", workload, desired, protection::describe_target(context, namespace));
+
+    loop {
+        let current = workloads::get_workload(workload, context, namespace)
+            .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error getting {}: {}", workload, error.trim())));
+        let mut pods = pods::get_pods(context, namespace, &selectors, false)
+            .unwrap_or_else(|error| display::print_error_and_exit(&format!("Error listing pods of {}: {}", workload, error.trim())));
+        pods.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+        let rows: Vec<_> = pods.iter().map(|pod| pods::pod_row(pod, None)).collect();
+
+        let status = &current.status;
+        let done = is_scaled(&current, desired);
+        let replicas = format!("Desired: {}  Current: {}  Ready: {}", desired, status.replicas, status.ready_replicas);
+        let state = if done {
+            "✅ All replicas ready".green().to_string()
+        } else {
+            format!("⏳ Waiting: {} of {} replicas ready...", status.ready_replicas, desired).yellow().to_string()
+        };
+        let title = format!("{} - updated {} (Ctrl+C to stop)", title, time::now_rfc3339());
+        display::print_workload_dashboard(&title, &replicas, &state, &rows, PodColumns::default());
+
+        if done {
+            display::print_success(&format!("{} runs {} ready replica(s)", workload, desired));
+            return;
+        }
+        thread::sleep(REFRESH_INTERVAL);
+    }
+}
+
+/// Whether the controller has seen the new replica count and exactly that many pods are ready
+fn is_scaled(workload: &k8s::Deployment, desired: u32) -> bool {
+    let status = &workload.status;
+    let observed = workload
+        .metadata
+        .generation
+        .is_none_or(|generation| status.observed_generation.is_some_and(|observed| observed >= generation));
+    observed && status.replicas == desired && status.ready_replicas == desired
+}
@@ -0,0 +1,146 @@
+use crate::{kubectl, utils, display, k8s};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Kinds of workloads that own pods
+#[derive(Clone, Copy, PartialEq)]
+pub enum WorkloadKind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Job,
+}
+
+impl WorkloadKind {
+    /// Parse a kubectl-style resource prefix (e.g. "deploy", "sts", "job")
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix.to_lowercase().as_str() {
+            "deploy" | "deployment" | "deployments" => Some(Self::Deployment),
+            "sts" | "statefulset" | "statefulsets" => Some(Self::StatefulSet),
+            "ds" | "daemonset" | "daemonsets" => Some(Self::DaemonSet),
+            "rs" | "replicaset" | "replicasets" => Some(Self::ReplicaSet),
+            "job" | "jobs" => Some(Self::Job),
+            _ => None,
+        }
+    }
+
+    /// Resource name as understood by kubectl
+    pub fn resource(&self) -> &'static str {
+        match self {
+            Self::Deployment => "deployment",
+            Self::StatefulSet => "statefulset",
+            Self::DaemonSet => "daemonset",
+            Self::ReplicaSet => "replicaset",
+            Self::Job => "job",
+        }
+    }
+}
+
+/// A resolved workload, displayed as "kind/name"
+#[derive(Clone)]
+pub struct Workload {
+    pub kind: WorkloadKind,
+    pub name: String,
+}
+
+impl fmt::Display for Workload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.kind.resource(), self.name)
+    }
+}
+
+/// Label selector as found in workload specs
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LabelSelector {
+    #[serde(default)]
+    match_labels: BTreeMap<String, String>,
+    #[serde(default)]
+    match_expressions: Vec<LabelSelectorRequirement>,
+}
+
+/// A single set-based selector requirement
+#[derive(Deserialize)]
+struct LabelSelectorRequirement {
+    key: String,
+    operator: String,
+    #[serde(default)]
+    values: Vec<String>,
+}
+
+/// Split a "kind/pattern" target into a workload kind and name pattern
+/// Returns None if the target has no recognised workload prefix
+pub fn parse_workload_target(target: &str) -> Option<(WorkloadKind, &str)> {
+    let (prefix, pattern) = target.split_once('/')?;
+    WorkloadKind::from_prefix(prefix).map(|kind| (kind, pattern))
+}
+
+/// Find all workloads of a kind matching a name pattern
+pub fn find_workloads(kind: WorkloadKind, pattern: &str, context: Option<&str>, namespace: Option<&str>) -> Vec<String> {
+    display::print_working(&format!("Resolving {}s with pattern {}...", kind.resource(), pattern));
+    let prefix = format!("{}.", kind.resource());
+    match kubectl::execute_with_context(&["get", kind.resource(), "-o", "name"], context, namespace) {
+        Ok(output) => {
+            output
+                .lines()
+                .filter_map(|line| line.trim().split_once('/'))
+                .filter(|(resource, _)| resource.starts_with(&prefix) || *resource == kind.resource())
+                .map(|(_, name)| name.to_string())
+                .filter(|name| utils::matches_pattern(pattern, name))
+                .collect()
+        }
+        Err(error) => {
+            display::print_error(&format!("Error finding {}s: {}", kind.resource(), error));
+            Vec::new()
+        }
+    }
+}
+
+/// Select a workload by pattern with user interaction if multiple matches
+pub fn select_workload(kind: WorkloadKind, pattern: &str, context: Option<&str>, namespace: Option<&str>) -> Option<Workload> {
+    let matches = find_workloads(kind, pattern, context, namespace);
+    utils::select_from_matches(matches, pattern, kind.resource())
+        .map(|name| Workload { kind, name })
+}
+
+/// Get a deployment or statefulset with its spec and status
+pub fn get_workload(workload: &Workload, context: Option<&str>, namespace: Option<&str>) -> Result<k8s::Deployment, String> {
+    let output = kubectl::execute_with_context(&["get", &workload.to_string(), "-o", "json"], context, namespace)?;
+    k8s::parse(&output, workload.kind.resource())
+}
+
+/// Get the pod label selector of a workload in kubectl `-l` syntax
+pub fn get_pod_selector(workload: &Workload, context: Option<&str>, namespace: Option<&str>) -> Result<String, String> {
+    let resource = workload.to_string();
+    let output = kubectl::execute_with_context(
+        &["get", &resource, "-o", "jsonpath={.spec.selector}"],
+        context,
+        namespace,
+    )?;
+
+    let selector: LabelSelector = serde_json::from_str(output.trim())
+        .map_err(|e| format!("Failed to parse selector of {}: {}", resource, e))?;
+
+    let mut requirements: Vec<String> = selector.match_labels
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+
+    for expression in &selector.match_expressions {
+        let requirement = match expression.operator.as_str() {
+            "In" => format!("{} in ({})", expression.key, expression.values.join(",")),
+            "NotIn" => format!("{} notin ({})", expression.key, expression.values.join(",")),
+            "Exists" => expression.key.to_string(),
+            "DoesNotExist" => format!("!{}", expression.key),
+            other => return Err(format!("Unsupported selector operator '{}' on {}", other, resource)),
+        };
+        requirements.push(requirement);
+    }
+
+    if requirements.is_empty() {
+        return Err(format!("{} has an empty pod selector", resource));
+    }
+    Ok(requirements.join(","))
+}
@@ -1,12 +1,20 @@
 use tabled::{Table, Tabled, settings::{
-    Style, object::{Rows, Columns, Cell}, Color
+    Style, Disable, object::{Rows, Columns, Cell}, Color, location::ByColumnName
 }};
 use owo_colors::OwoColorize;
 use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// When set, all regular output goes to stderr so stdout stays clean for shell evaluation
+static OUTPUT_TO_STDERR: AtomicBool = AtomicBool::new(false);
 
 /// Represents a pod for table display
 #[derive(Tabled)]
 pub struct PodDisplay {
+    #[tabled(rename = "Context")]
+    pub context: String,
+    #[tabled(rename = "Namespace")]
+    pub namespace: String,
     #[tabled(rename = "Pod Name")]
     pub name: String,
     #[tabled(rename = "Ready")]
@@ -17,6 +25,65 @@ pub struct PodDisplay {
     pub restarts: String,
     #[tabled(rename = "Age")]
     pub age: String,
+    #[tabled(rename = "Revision")]
+    pub revision: String,
+    #[tabled(rename = "CPU")]
+    pub cpu: String,
+    #[tabled(rename = "CPU Req/Lim")]
+    pub cpu_requests: String,
+    #[tabled(rename = "Memory")]
+    pub memory: String,
+    #[tabled(rename = "Mem Req/Lim")]
+    pub memory_requests: String,
+    #[tabled(rename = "Node")]
+    pub node: String,
+    #[tabled(rename = "IP")]
+    pub ip: String,
+    #[tabled(rename = "QoS")]
+    pub qos: String,
+    #[tabled(rename = "Containers")]
+    pub containers: String,
+    #[tabled(rename = "Owner")]
+    pub owner: String,
+    /// CPU usage is over the warning share of the limit
+    #[tabled(skip)]
+    pub cpu_hot: bool,
+    /// Memory usage is over the warning share of the limit
+    #[tabled(skip)]
+    pub memory_hot: bool,
+    /// The pod had warning events recently
+    #[tabled(skip)]
+    pub warned: bool,
+}
+
+/// A change to a pod since the previous refresh of `kubix pods --watch`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PodChange {
+    New,
+    Removed,
+    StatusChanged,
+    Restarted,
+}
+
+/// Columns of the pods table only shown with --wide
+const WIDE_POD_COLUMNS: [&str; 5] = ["Node", "IP", "QoS", "Containers", "Owner"];
+
+/// Columns of the pods table only shown with --usage and `kubix top`
+const USAGE_POD_COLUMNS: [&str; 4] = ["CPU", "CPU Req/Lim", "Memory", "Mem Req/Lim"];
+
+/// Which optional columns the pods table shows
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PodColumns {
+    /// The pods come from several contexts
+    pub context: bool,
+    /// The pods come from several namespaces
+    pub namespace: bool,
+    /// Node, IP, QoS, containers and owner
+    pub wide: bool,
+    /// CPU and memory usage with requests and limits
+    pub usage: bool,
+    /// Deployment revision each pod belongs to
+    pub revision: bool,
 }
 
 /// Represents a command for table display
@@ -46,6 +113,78 @@ pub struct InterpreterDisplay {
     pub interpreter: String,
 }
 
+/// Represents a log highlight rule for table display
+#[derive(Tabled)]
+pub struct HighlightDisplay {
+    #[tabled(rename = "Pattern")]
+    pub pattern: String,
+    #[tabled(rename = "Color")]
+    pub color: String,
+}
+
+/// Represents a context alias for table display
+#[derive(Tabled)]
+pub struct AliasDisplay {
+    #[tabled(rename = "Alias")]
+    pub alias: String,
+    #[tabled(rename = "Context")]
+    pub context: String,
+}
+
+/// Represents a protected context pattern for table display
+#[derive(Tabled)]
+pub struct ProtectedContextDisplay {
+    #[tabled(rename = "Protected Context Pattern")]
+    pub pattern: String,
+}
+
+/// Represents an audit log entry for table display
+#[derive(Tabled)]
+pub struct AuditDisplay {
+    #[tabled(rename = "Time")]
+    pub time: String,
+    #[tabled(rename = "User")]
+    pub user: String,
+    #[tabled(rename = "Context")]
+    pub context: String,
+    #[tabled(rename = "Namespace")]
+    pub namespace: String,
+    #[tabled(rename = "Action")]
+    pub action: String,
+    #[tabled(rename = "Pod")]
+    pub pod: String,
+    #[tabled(rename = "Command")]
+    pub command: String,
+    #[tabled(rename = "Exit")]
+    pub exit: String,
+}
+
+/// Represents a health check result for table display
+#[derive(Tabled)]
+pub struct DoctorDisplay {
+    #[tabled(rename = "Context")]
+    pub scope: String,
+    #[tabled(rename = "Check")]
+    pub check: String,
+    #[tabled(rename = "Result")]
+    pub result: String,
+    #[tabled(rename = "Details")]
+    pub details: String,
+}
+
+/// Represents the per-context result of a fan-out command for table display
+#[derive(Tabled)]
+pub struct FanoutDisplay {
+    #[tabled(rename = "Context")]
+    pub context: String,
+    #[tabled(rename = "Pod")]
+    pub pod: String,
+    #[tabled(rename = "Exit")]
+    pub exit: String,
+    #[tabled(rename = "Output")]
+    pub output: String,
+}
+
 /// Represents settings for table display
 #[derive(Tabled)]
 pub struct SettingsDisplay {
@@ -57,11 +196,161 @@ pub struct SettingsDisplay {
     pub description: String,
 }
 
+/// Represents a property of a described object for table display
+#[derive(Tabled)]
+pub struct PropertyDisplay {
+    #[tabled(rename = "Property")]
+    pub property: String,
+    #[tabled(rename = "Value")]
+    pub value: String,
+}
+
+/// Represents a pod condition for table display
+#[derive(Tabled)]
+pub struct ConditionDisplay {
+    #[tabled(rename = "Condition")]
+    pub condition: String,
+    #[tabled(rename = "Status")]
+    pub status: String,
+    #[tabled(rename = "Reason")]
+    pub reason: String,
+    #[tabled(rename = "Message")]
+    pub message: String,
+}
+
+/// Represents a container of a described pod for table display
+#[derive(Tabled)]
+pub struct ContainerDetailDisplay {
+    #[tabled(rename = "Container")]
+    pub container: String,
+    #[tabled(rename = "Image")]
+    pub image: String,
+    #[tabled(rename = "State")]
+    pub state: String,
+    #[tabled(rename = "Ready")]
+    pub ready: String,
+    #[tabled(rename = "Restarts")]
+    pub restarts: String,
+    #[tabled(rename = "Last Termination")]
+    pub last_termination: String,
+    #[tabled(rename = "Probes")]
+    pub probes: String,
+}
+
+/// Represents a pod volume for table display
+#[derive(Tabled)]
+pub struct VolumeDisplay {
+    #[tabled(rename = "Volume")]
+    pub volume: String,
+    #[tabled(rename = "Source")]
+    pub source: String,
+    #[tabled(rename = "Mounted At")]
+    pub mounts: String,
+}
+
+/// Represents a cluster event for table display
+#[derive(Tabled)]
+pub struct EventDisplay {
+    #[tabled(rename = "Last Seen")]
+    pub last_seen: String,
+    #[tabled(rename = "Type")]
+    pub event_type: String,
+    #[tabled(rename = "Reason")]
+    pub reason: String,
+    #[tabled(rename = "Object")]
+    pub object: String,
+    #[tabled(rename = "Count")]
+    pub count: String,
+    #[tabled(rename = "Message")]
+    pub message: String,
+}
+
+/// Represents a deployment revision for table display
+#[derive(Tabled)]
+pub struct RevisionDisplay {
+    #[tabled(rename = "Revision")]
+    pub revision: String,
+    #[tabled(rename = "Created")]
+    pub created: String,
+    #[tabled(rename = "Pods")]
+    pub pods: String,
+    #[tabled(rename = "Images")]
+    pub images: String,
+    #[tabled(rename = "Change Cause")]
+    pub change_cause: String,
+    /// The revision the deployment currently runs
+    #[tabled(skip)]
+    pub current: bool,
+}
+
 /// Represents a context for table display
 #[derive(Tabled)]
 pub struct ContextDisplay {
     #[tabled(rename = "Context")]
     pub context: String,
+    #[tabled(rename = "Alias")]
+    pub alias: String,
+    #[tabled(rename = "Cluster")]
+    pub cluster: String,
+    #[tabled(rename = "User")]
+    pub user: String,
+    #[tabled(rename = "Namespace")]
+    pub namespace: String,
+    #[tabled(rename = "Server")]
+    pub server: String,
+    #[tabled(rename = "Current")]
+    pub current: String,
+}
+
+/// Represents a namespace for table display
+#[derive(Tabled)]
+pub struct NamespaceDisplay {
+    #[tabled(rename = "Namespace")]
+    pub namespace: String,
+    #[tabled(rename = "Current")]
+    pub current: String,
+}
+
+/// Represents a log statistic for table display
+#[derive(Tabled)]
+pub struct LogStatDisplay {
+    #[tabled(rename = "Metric")]
+    pub metric: String,
+    #[tabled(rename = "Value")]
+    pub value: String,
+}
+
+/// Represents a log level count for table display
+#[derive(Tabled)]
+pub struct LogLevelDisplay {
+    #[tabled(rename = "Level")]
+    pub level: String,
+    #[tabled(rename = "Lines")]
+    pub count: String,
+    #[tabled(rename = "Share")]
+    pub share: String,
+}
+
+/// Represents a frequent log message for table display
+#[derive(Tabled)]
+pub struct LogMessageDisplay {
+    #[tabled(rename = "Count")]
+    pub count: String,
+    #[tabled(rename = "Message")]
+    pub message: String,
+}
+
+/// Represents a context history entry for table display
+#[derive(Tabled)]
+pub struct ContextHistoryDisplay {
+    #[tabled(rename = "#")]
+    pub number: String,
+    #[tabled(rename = "Context")]
+    pub context: String,
+    #[tabled(rename = "Last Used")]
+    pub last_used: String,
+    #[tabled(rename = "When")]
+    pub when: String,
     #[tabled(rename = "Current")]
     pub current: String,
 }
@@ -96,48 +385,8 @@ fn colorize_status(status: &str) -> Color {
     }
 }
 
-/// Print pods in a beautiful table format
-pub fn print_pods_table(pods_output: &str, pattern: Option<&str>) {
-    let lines: Vec<&str> = pods_output.lines().collect();
-    if lines.is_empty() {
-        print_line(&"No pods found".yellow().to_string());
-        return;
-    }
-
-    // Parse kubectl output into structured data
-    let mut pod_displays = Vec::new();
-    
-    for line in lines.iter().skip(1) { // Skip header
-        if line.trim().is_empty() {
-            continue;
-        }
-        
-        // Parse kubectl output (space-separated columns)
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() >= 5 {
-            let name = parts[0].to_string();
-            let ready = parts[1].to_string();
-            let status = parts[2].to_string();
-            let restarts = parts[3].to_string();
-            let age = parts[4].to_string();
-            
-            // Apply pattern filtering if provided
-            if let Some(p) = pattern {
-                if !line.contains(p) {
-                    continue;
-                }
-            }
-            
-            pod_displays.push(PodDisplay {
-                name,
-                ready,
-                status,
-                restarts,
-                age,
-            });
-        }
-    }
-    
+/// Print pods in a beautiful table format, with the optional columns that apply
+pub fn print_pods_table(pod_displays: &[PodDisplay], pattern: Option<&str>, columns: PodColumns) {
     if pod_displays.is_empty() {
         if let Some(p) = pattern {
             print_line(&format!("No pods found matching pattern: '{}'", p).yellow().to_string());
@@ -147,19 +396,232 @@ pub fn print_pods_table(pods_output: &str, pattern: Option<&str>) {
         return;
     }
     
-    let mut table = Table::new(&pod_displays);
-    style_table(&mut table);
-    for (i, d) in pod_displays.iter().enumerate() {
-        table.modify(Cell::new(i + 1, 2), colorize_status(&d.status));
-    }
-    
+    let table = build_pods_table(pod_displays, &[], columns);
     let header = if let Some(p) = pattern {
         format!("📋 Found {} pod(s) matching '{}':", pod_displays.len(), p).cyan().bold().to_string()
     } else {
         "📋 Pods:".cyan().bold().to_string()
     };
     
-    print_lines(&[&header, &table.to_string()]);
+    let mut lines = vec![header, table.to_string()];
+    if pod_displays.iter().any(|pod| pod.warned) {
+        lines.push(format!("{} warning events in the last hour - see `kubix events <pod>`", "⚠".yellow()));
+    }
+    let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+    print_lines(&lines);
+}
+
+/// Redraw the `kubix pods --watch` dashboard in place: title, counts by status and the pods table
+/// Rows are highlighted by what changed since the previous refresh
+pub fn print_pods_dashboard(title: &str, pod_displays: &[PodDisplay], changes: &[Option<PodChange>], columns: PodColumns) {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for (pod, change) in pod_displays.iter().zip(changes) {
+        if *change == Some(PodChange::Removed) {
+            continue;
+        }
+        match counts.iter_mut().find(|(status, _)| *status == pod.status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((pod.status.clone(), 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    
+    let summary = if counts.is_empty() {
+        "No pods".yellow().to_string()
+    } else {
+        counts
+            .iter()
+            .map(|(status, count)| {
+                let color = colorize_status(status);
+                format!("{}{}{}: {}", color.get_prefix(), status, color.get_suffix(), count)
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+    let legend = format!(
+        "{} new  {} removed  {} status changed  {} restarted",
+        "■".green(), "■".bright_black(), "■".yellow(), "■".red()
+    );
+    
+    // Clear the screen and move the cursor home before drawing
+    let mut lines = vec!["\x1b[2J\x1b[H".to_string() + &title.cyan().bold().to_string(), summary, legend];
+    if !pod_displays.is_empty() {
+        lines.push(build_pods_table(pod_displays, changes, columns).to_string());
+    }
+    let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+    print_lines(&lines);
+}
+
+/// Redraw a workload progress view (`kubix rollout`, `kubix scale --wait`) in place:
+/// title, replica counts, progress and the workload's pods
+pub fn print_workload_dashboard(title: &str, replicas: &str, state: &str, pod_displays: &[PodDisplay], columns: PodColumns) {
+    // Clear the screen and move the cursor home before drawing
+    let mut lines = vec!["\x1b[2J\x1b[H".to_string() + &title.cyan().bold().to_string(), replicas.to_string(), state.to_string()];
+    if pod_displays.is_empty() {
+        lines.push("No pods".yellow().to_string());
+    } else {
+        lines.push(build_pods_table(pod_displays, &[], columns).to_string());
+    }
+    let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+    print_lines(&lines);
+}
+
+/// Build the styled pods table, colouring statuses and highlighting changed rows
+fn build_pods_table(pod_displays: &[PodDisplay], changes: &[Option<PodChange>], columns: PodColumns) -> Table {
+    let mut hidden: Vec<&str> = Vec::new();
+    if !columns.context {
+        hidden.push("Context");
+    }
+    if !columns.namespace {
+        hidden.push("Namespace");
+    }
+    if !columns.wide {
+        hidden.extend(WIDE_POD_COLUMNS);
+    }
+    if !columns.usage {
+        hidden.extend(USAGE_POD_COLUMNS);
+    }
+    if !columns.revision {
+        hidden.push("Revision");
+    }
+    
+    // Locate the columns to colour among the visible ones
+    let visible: Vec<String> = PodDisplay::headers()
+        .into_iter()
+        .map(|header| header.to_string())
+        .filter(|header| !hidden.contains(&header.as_str()))
+        .collect();
+    let column_index = |name: &str| visible.iter().position(|header| header == name).unwrap_or_default();
+    let (status_column, restarts_column) = (column_index("Status"), column_index("Restarts"));
+    let (cpu_column, memory_column) = (column_index("CPU"), column_index("Memory"));
+    let name_column = column_index("Pod Name");
+    
+    let mut table = Table::new(pod_displays);
+    for column in hidden {
+        table.with(Disable::column(ByColumnName::new(column)));
+    }
+    style_table(&mut table);
+    for (i, d) in pod_displays.iter().enumerate() {
+        table.modify(Cell::new(i + 1, status_column), colorize_status(&d.status));
+        if d.warned {
+            table.modify(Cell::new(i + 1, name_column), Color::FG_YELLOW);
+        }
+        if columns.usage && d.cpu_hot {
+            table.modify(Cell::new(i + 1, cpu_column), Color::FG_RED);
+        }
+        if columns.usage && d.memory_hot {
+            table.modify(Cell::new(i + 1, memory_column), Color::FG_RED);
+        }
+        match changes.get(i).copied().flatten() {
+            Some(PodChange::New) => {
+                table.modify(Rows::single(i + 1), Color::FG_BLACK | Color::BG_GREEN);
+            }
+            Some(PodChange::Removed) => {
+                table.modify(Rows::single(i + 1), Color::FG_BRIGHT_BLACK);
+                table.modify(Cell::new(i + 1, status_column), Color::FG_BRIGHT_BLACK);
+            }
+            Some(PodChange::StatusChanged) => {
+                table.modify(Cell::new(i + 1, status_column), Color::FG_BLACK | Color::BG_YELLOW);
+            }
+            Some(PodChange::Restarted) => {
+                table.modify(Cell::new(i + 1, restarts_column), Color::FG_WHITE | Color::BG_RED);
+            }
+            None => {}
+        }
+    }
+    table
+}
+
+/// Print the overview of a described object as a property/value table, colouring its status
+pub fn print_properties_table(title: &str, properties: &[PropertyDisplay]) {
+    let mut table = Table::new(properties);
+    style_table(&mut table);
+    for (i, property) in properties.iter().enumerate() {
+        if property.property == "Status" {
+            table.modify(Cell::new(i + 1, 1), colorize_status(&property.value));
+        }
+    }
+    print_lines(&[&title.cyan().bold().to_string(), &table.to_string()]);
+}
+
+/// Print pod conditions, green when met and red when not
+pub fn print_conditions_table(conditions: &[ConditionDisplay]) {
+    if conditions.is_empty() {
+        return;
+    }
+
+    let mut table = Table::new(conditions);
+    style_table(&mut table);
+    for (i, condition) in conditions.iter().enumerate() {
+        let color = if condition.status == "True" { Color::FG_GREEN } else { Color::FG_RED };
+        table.modify(Cell::new(i + 1, 1), color);
+    }
+    print_lines(&["", &"🚦 Conditions:".cyan().bold().to_string(), &table.to_string()]);
+}
+
+/// Print the containers of a described pod, colouring their state and failed terminations
+pub fn print_container_details_table(containers: &[ContainerDetailDisplay]) {
+    let mut table = Table::new(containers);
+    style_table(&mut table);
+    for (i, container) in containers.iter().enumerate() {
+        table.modify(Cell::new(i + 1, 2), colorize_status(&container.state));
+        let ready_color = if container.ready == "✓" { Color::FG_GREEN } else { Color::FG_RED };
+        table.modify(Cell::new(i + 1, 3), ready_color);
+        if container.last_termination != "-" && !container.last_termination.starts_with("Completed (exit 0)") {
+            table.modify(Cell::new(i + 1, 5), Color::FG_RED);
+        }
+    }
+    print_lines(&["", &"📦 Containers:".cyan().bold().to_string(), &table.to_string()]);
+}
+
+/// Print the volumes of a described pod
+pub fn print_volumes_table(volumes: &[VolumeDisplay]) {
+    if volumes.is_empty() {
+        return;
+    }
+
+    let mut table = Table::new(volumes);
+    style_table(&mut table);
+    print_lines(&["", &"💾 Volumes:".cyan().bold().to_string(), &table.to_string()]);
+}
+
+/// Print events in a table, highlighting warnings
+/// The object column is left out when all events are about the same object
+pub fn print_events_table(header: &str, events: &[EventDisplay], show_object: bool) {
+    if events.is_empty() {
+        print_lines(&["", &header.cyan().bold().to_string(), &"No events found".yellow().to_string()]);
+        return;
+    }
+
+    let mut table = Table::new(events);
+    if !show_object {
+        table.with(Disable::column(ByColumnName::new("Object")));
+    }
+    style_table(&mut table);
+    for (i, event) in events.iter().enumerate() {
+        if event.event_type == "Warning" {
+            table.modify(Cell::new(i + 1, 1), Color::FG_YELLOW);
+            table.modify(Cell::new(i + 1, 2), Color::FG_YELLOW);
+        }
+    }
+    print_lines(&["", &header.cyan().bold().to_string(), &table.to_string()]);
+}
+
+/// Print the revisions of a deployment, highlighting the current one
+pub fn print_revisions_table(header: &str, revisions: &[RevisionDisplay]) {
+    if revisions.is_empty() {
+        print_lines(&[&header.cyan().bold().to_string(), &"No revisions found".yellow().to_string()]);
+        return;
+    }
+
+    let mut table = Table::new(revisions);
+    style_table(&mut table);
+    for (i, revision) in revisions.iter().enumerate() {
+        if revision.current {
+            table.modify(Rows::single(i + 1), Color::FG_GREEN);
+        }
+    }
+    print_lines(&[&header.cyan().bold().to_string(), &table.to_string()]);
 }
 
 /// Print commands in a beautiful table format
@@ -231,33 +693,106 @@ pub fn print_interpreters_table(interpreters: &std::collections::HashMap<String,
     print_lines(&["", &header, &table.to_string()]);
 }
 
-/// Print contexts in a beautiful table format
-pub fn print_contexts_table(contexts_output: &str, current_context: Option<&str>) {
-    let lines: Vec<&str> = contexts_output.lines().collect();
-    if lines.is_empty() {
-        print_line(&"No contexts found".yellow().to_string());
+/// Print log highlight rules in a beautiful table format
+pub fn print_highlights_table(highlights: &std::collections::HashMap<String, String>) {
+    if highlights.is_empty() {
         return;
     }
 
-    let mut context_displays = Vec::new();
+    let mut highlight_displays: Vec<HighlightDisplay> = highlights
+        .iter()
+        .map(|(pattern, color)| HighlightDisplay {
+            pattern: pattern.to_string(),
+            color: color.to_string(),
+        })
+        .collect();
     
-    for line in lines {
-        let context = line.trim();
-        if !context.is_empty() {
-            let is_current = current_context.map_or(false, |current| current == context);
-            let current_marker = if is_current { "✓" } else { "" };
-            
-            context_displays.push(ContextDisplay {
-                context: context.to_string(),
-                current: current_marker.to_string(),
-            });
-        }
+    highlight_displays.sort_by(|a, b| a.pattern.cmp(&b.pattern));
+    
+    let mut table = Table::new(&highlight_displays);
+    style_table(&mut table);
+    
+    let header = "🎨 Log Highlights:".yellow().bold().to_string();
+    print_lines(&["", &header, &table.to_string()]);
+}
+
+/// Print context aliases in a beautiful table format
+pub fn print_aliases_table(aliases: &std::collections::HashMap<String, String>) {
+    if aliases.is_empty() {
+        return;
+    }
+
+    let mut alias_displays: Vec<AliasDisplay> = aliases
+        .iter()
+        .map(|(alias, context)| AliasDisplay {
+            alias: alias.to_string(),
+            context: context.to_string(),
+        })
+        .collect();
+    
+    alias_displays.sort_by(|a, b| a.alias.cmp(&b.alias));
+    
+    let mut table = Table::new(&alias_displays);
+    style_table(&mut table);
+    
+    let header = "🏷️ Context Aliases:".yellow().bold().to_string();
+    print_lines(&["", &header, &table.to_string()]);
+}
+
+/// Print protected context patterns in a beautiful table format
+pub fn print_protected_contexts_table(patterns: &[String]) {
+    if patterns.is_empty() {
+        return;
     }
+
+    let protected_displays: Vec<ProtectedContextDisplay> = patterns
+        .iter()
+        .map(|pattern| ProtectedContextDisplay {
+            pattern: pattern.to_string(),
+        })
+        .collect();
+    
+    let mut table = Table::new(&protected_displays);
+    style_table(&mut table);
     
-    if context_displays.is_empty() {
+    let header = "🛡️ Protected Contexts:".yellow().bold().to_string();
+    print_lines(&["", &header, &table.to_string()]);
+}
+
+/// Print a red banner for commands running against a protected context
+pub fn print_protected_banner(context: &str) {
+    let banner = format!("  🛑 PROTECTED CONTEXT: {}  ", context);
+    eprint_line(&banner.white().on_red().bold().to_string());
+}
+
+/// Print exactly which resources an action will affect, before asking for confirmation
+pub fn print_affected_resources(header: &str, resources: &[String]) {
+    let mut lines = vec![header.yellow().bold().to_string()];
+    lines.extend(resources.iter().map(|resource| format!("  • {}", resource.bright_white())));
+    let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+    print_lines(&lines);
+}
+
+/// Print contexts in a beautiful table format, with details from the kubeconfig
+pub fn print_contexts_table(kubeconfig: &crate::kubeconfig::KubeConfig, current_context: Option<&str>, config: &crate::commands::config::KubixConfig) {
+    if kubeconfig.contexts.is_empty() {
         print_line(&"No contexts found".yellow().to_string());
         return;
     }
+
+    let context_displays: Vec<ContextDisplay> = kubeconfig
+        .contexts
+        .iter()
+        .map(|named| ContextDisplay {
+            context: named.name.to_string(),
+            alias: config.aliases_for(&named.name).join(", "),
+            cluster: named.context.cluster.to_string(),
+            user: named.context.user.to_string(),
+            namespace: named.context.namespace.clone().unwrap_or_default(),
+            server: kubeconfig.server(&named.context.cluster).unwrap_or_default().to_string(),
+            current: if current_context == Some(named.name.as_str()) { "✓" } else { "" }.to_string(),
+        })
+        .collect();
     
     let mut table = Table::new(&context_displays);
     style_table(&mut table);
@@ -274,6 +809,256 @@ pub fn print_contexts_table(contexts_output: &str, current_context: Option<&str>
     print_lines(&[&header, &table.to_string()]);
 }
 
+/// Print a log statistics summary as tables and a sparkline
+/// Every counted line has a level (NONE included), so no levels means there was nothing to summarize
+pub fn print_log_stats(
+    summary: &[LogStatDisplay],
+    levels: &[LogLevelDisplay],
+    messages: &[LogMessageDisplay],
+    lines_per_bucket: &[usize],
+    minutes_per_bucket: usize,
+) {
+    if levels.is_empty() {
+        print_line(&"No log lines to summarize".yellow().to_string());
+        return;
+    }
+
+    let mut table = Table::new(summary);
+    style_table(&mut table);
+    print_lines(&[&"📊 Summary:".cyan().bold().to_string(), &table.to_string()]);
+
+    let mut table = Table::new(levels);
+    style_table(&mut table);
+    for (i, d) in levels.iter().enumerate() {
+        table.modify(Cell::new(i + 1, 0), colorize_level(&d.level));
+    }
+    print_lines(&["", &"🏷️  Levels:".cyan().bold().to_string(), &table.to_string()]);
+
+    if !messages.is_empty() {
+        let mut table = Table::new(messages);
+        style_table(&mut table);
+        let header = format!("🔁 Top {} messages:", messages.len()).cyan().bold().to_string();
+        print_lines(&["", &header, &table.to_string()]);
+    }
+
+    if !lines_per_bucket.is_empty() {
+        let peak = lines_per_bucket.iter().max().copied().unwrap_or(0);
+        let unit = if minutes_per_bucket == 1 {
+            "minute".to_string()
+        } else {
+            format!("{} minutes", minutes_per_bucket)
+        };
+        print_lines(&[
+            "",
+            &format!("📈 Lines per {} (peak {}):", unit, peak).cyan().bold().to_string(),
+            &sparkline(lines_per_bucket).bright_green().to_string(),
+        ]);
+    }
+}
+
+/// Render values as a unicode sparkline
+pub fn sparkline(values: &[usize]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().max().copied().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| {
+            if max == 0 {
+                BARS[0]
+            } else {
+                BARS[(value * (BARS.len() - 1)).div_ceil(max)]
+            }
+        })
+        .collect()
+}
+
+/// Apply color to a log level name
+fn colorize_level(level: &str) -> Color {
+    match level {
+        "ERROR" | "FATAL" => Color::FG_RED,
+        "WARN" => Color::FG_YELLOW,
+        "INFO" => Color::FG_GREEN,
+        "DEBUG" | "TRACE" => Color::FG_BLUE,
+        _ => Color::FG_WHITE,
+    }
+}
+
+/// Shorten text to a maximum number of characters, adding an ellipsis when cut
+pub fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+        format!("{}…", cut)
+    }
+}
+
+/// Print recently used contexts in a beautiful table format
+pub fn print_context_history_table(entries: &[crate::history::HistoryEntry], current_context: Option<&str>) {
+    if entries.is_empty() {
+        print_line(&"No context history yet - switch contexts with 'kubix ctx <pattern>'".yellow().to_string());
+        return;
+    }
+
+    let history_displays: Vec<ContextHistoryDisplay> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| ContextHistoryDisplay {
+            number: (i + 1).to_string(),
+            context: entry.name.to_string(),
+            last_used: entry.timestamp.to_string(),
+            when: crate::time::format_ago(&entry.timestamp),
+            current: if current_context == Some(entry.name.as_str()) { "✓" } else { "" }.to_string(),
+        })
+        .collect();
+
+    let mut table = Table::new(&history_displays);
+    style_table(&mut table);
+    for (i, display) in history_displays.iter().enumerate() {
+        if display.current == "✓" {
+            table.modify(Rows::single(i + 1), Color::FG_GREEN);
+        }
+    }
+
+    let header = "🕘 Recent contexts:".cyan().bold().to_string();
+    print_lines(&[&header, &table.to_string()]);
+}
+
+/// Print namespaces in a table, marking the current default namespace
+pub fn print_namespaces_table(namespaces: &[String], current_namespace: &str) {
+    if namespaces.is_empty() {
+        print_line(&"No namespaces found".yellow().to_string());
+        return;
+    }
+
+    let namespace_displays: Vec<NamespaceDisplay> = namespaces
+        .iter()
+        .map(|namespace| NamespaceDisplay {
+            namespace: namespace.to_string(),
+            current: if namespace == current_namespace { "✓" } else { "" }.to_string(),
+        })
+        .collect();
+
+    let mut table = Table::new(&namespace_displays);
+    style_table(&mut table);
+    for (i, display) in namespace_displays.iter().enumerate() {
+        if display.current == "✓" {
+            table.modify(Rows::single(i + 1), Color::FG_GREEN);
+        }
+    }
+
+    let header = "📁 Available namespaces:".cyan().bold().to_string();
+    print_lines(&[&header, &table.to_string()]);
+}
+
+/// Print audit log entries in a table, coloring failed actions red and declined ones yellow
+pub fn print_audit_table(entries: &[crate::audit::AuditEntry], total: usize) {
+    if entries.is_empty() {
+        print_line(&"No audit entries found".yellow().to_string());
+        return;
+    }
+
+    let audit_displays: Vec<AuditDisplay> = entries
+        .iter()
+        .map(|entry| AuditDisplay {
+            time: entry.timestamp.to_string(),
+            user: entry.user.to_string(),
+            context: entry.context.clone().unwrap_or_default(),
+            namespace: entry.namespace.clone().unwrap_or_default(),
+            action: entry.action.to_string(),
+            pod: entry.pod.clone().unwrap_or_default(),
+            command: match &entry.script_sha256 {
+                Some(hash) => format!("{} (sha256:{})", entry.command.as_deref().unwrap_or_default(), &hash[..hash.len().min(12)]),
+                None => truncate(entry.command.as_deref().unwrap_or_default(), 60),
+            },
+            exit: match entry.exit_code {
+                _ if entry.cancelled => "cancelled".to_string(),
+                Some(code) => code.to_string(),
+                None => "-".to_string(),
+            },
+        })
+        .collect();
+
+    let mut table = Table::new(&audit_displays);
+    style_table(&mut table);
+    for (i, entry) in entries.iter().enumerate() {
+        let color = match entry.exit_code {
+            _ if entry.cancelled => Color::FG_YELLOW,
+            Some(0) => Color::FG_GREEN,
+            _ => Color::FG_RED,
+        };
+        table.modify(Cell::new(i + 1, 7), color);
+    }
+
+    let header = if total > entries.len() {
+        format!("📜 Audit log (latest {} of {} entries):", entries.len(), total)
+    } else {
+        format!("📜 Audit log ({} entries):", entries.len())
+    };
+    print_lines(&[&header.cyan().bold().to_string(), &table.to_string()]);
+}
+
+/// Print health check results as a pass/fail table with a summary line
+pub fn print_doctor_table(results: &[crate::commands::doctor::CheckResult]) {
+    use crate::commands::doctor::CheckStatus;
+
+    let doctor_displays: Vec<DoctorDisplay> = results
+        .iter()
+        .map(|result| DoctorDisplay {
+            scope: result.scope.to_string(),
+            check: result.check.to_string(),
+            result: match result.status {
+                CheckStatus::Pass => "✅ PASS",
+                CheckStatus::Warn => "⚠️ WARN",
+                CheckStatus::Fail => "❌ FAIL",
+                CheckStatus::Skip => "⏭️ SKIP",
+            }.to_string(),
+            details: truncate(&result.details, 80),
+        })
+        .collect();
+
+    let mut table = Table::new(&doctor_displays);
+    style_table(&mut table);
+    for (i, result) in results.iter().enumerate() {
+        let color = match result.status {
+            CheckStatus::Pass => Color::FG_GREEN,
+            CheckStatus::Warn => Color::FG_YELLOW,
+            CheckStatus::Fail => Color::FG_RED,
+            CheckStatus::Skip => Color::FG_WHITE,
+        };
+        table.modify(Cell::new(i + 1, 2), color);
+    }
+
+    let count = |status: CheckStatus| results.iter().filter(|result| result.status == status).count();
+    let summary = format!(
+        "{} passed, {} warnings, {} failed, {} skipped",
+        count(CheckStatus::Pass), count(CheckStatus::Warn), count(CheckStatus::Fail), count(CheckStatus::Skip)
+    );
+    let header = "🩺 kubix doctor:".cyan().bold().to_string();
+    let summary = if count(CheckStatus::Fail) > 0 { summary.red().bold().to_string() } else { summary.green().bold().to_string() };
+    print_lines(&[&header, &table.to_string(), &summary]);
+}
+
+/// Print a per-context summary of a fan-out command
+pub fn print_fanout_table(rows: &[FanoutDisplay]) {
+    let mut table = Table::new(rows);
+    style_table(&mut table);
+    for (i, row) in rows.iter().enumerate() {
+        let color = if row.exit == "0" { Color::FG_GREEN } else { Color::FG_RED };
+        table.modify(Cell::new(i + 1, 2), color);
+    }
+
+    let failed = rows.iter().filter(|row| row.exit != "0").count();
+    let summary = format!("{} context(s), {} succeeded, {} failed", rows.len(), rows.len() - failed, failed);
+    let summary = if failed > 0 { summary.red().bold().to_string() } else { summary.green().bold().to_string() };
+    print_lines(&[&"🌐 Results per context:".cyan().bold().to_string(), &table.to_string(), &summary]);
+}
+
+/// Print a section header for one context's output of a fan-out command
+pub fn print_context_section(context: &str, detail: &str) {
+    print_line(&format!("{} {} {}", "──".bright_black(), context.bright_blue().bold(), detail.bright_black()));
+}
+
 /// Print selection items in a beautiful table format
 pub fn print_selection_table<T: Display>(items: &[T], resource_type: &str, details_fn: Option<fn(&T) -> String>) {
     if items.is_empty() {
@@ -306,6 +1091,11 @@ pub fn print_settings_table(settings: &crate::commands::config::Settings) {
             value: settings.script_delay_seconds.to_string(),
             description: "Time to wait before executing scripts (seconds)".to_string(),
         },
+        SettingsDisplay {
+            setting: "cache_ttl_seconds".to_string(),
+            value: settings.cache_ttl_seconds.to_string(),
+            description: "How long cached context, namespace and pod names stay fresh (seconds, 0 disables)".to_string(),
+        },
     ];
     
     let mut table = Table::new(&settings_displays);
@@ -351,14 +1141,32 @@ pub fn eprint_line(message: &str) {
     eprintln!("{}", message);
 }
 
+/// Redirect all regular output to stderr (used when stdout is evaluated by a shell)
+pub fn redirect_output_to_stderr() {
+    OUTPUT_TO_STDERR.store(true, Ordering::Relaxed);
+}
+
+/// Print a line to stdout even when output is redirected (e.g. shell exports)
+pub fn print_stdout_line(message: &str) {
+    println!("{}", message);
+}
+
 /// Centralized print line function
 pub fn print_line(message: &str) {
-    println!("{}", message);
+    if OUTPUT_TO_STDERR.load(Ordering::Relaxed) {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
 }
 
 /// Centralized print function
 pub fn print(message: &str) {
-    print!("{}", message);
+    if OUTPUT_TO_STDERR.load(Ordering::Relaxed) {
+        eprint!("{}", message);
+    } else {
+        print!("{}", message);
+    }
 }
 
 /// Print multiple lines efficiently in a single call
@@ -368,5 +1176,5 @@ pub fn print_lines(lines: &[&str]) {
     }
     
     let output = lines.join("\n");
-    println!("{}", output);
+    print_line(&output);
 }
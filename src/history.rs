@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::{display, time};
+
+/// Maximum number of entries kept per history list
+const MAX_ENTRIES: usize = 50;
+
+/// Persisted history of context and namespace switches, stored next to the kubix config
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    #[serde(default)]
+    pub contexts: Vec<HistoryEntry>,
+    /// Namespace switches, keyed by context
+    #[serde(default)]
+    pub namespaces: BTreeMap<String, Vec<HistoryEntry>>,
+}
+
+/// A single history entry - the name switched to and when
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HistoryEntry {
+    pub name: String,
+    pub timestamp: String,
+}
+
+impl History {
+    /// Load history using confy, falling back to an empty history
+    pub fn load() -> Self {
+        match confy::load("kubix", Some("history")) {
+            Ok(history) => history,
+            Err(err) => {
+                display::print_warning(&format!("Failed to load history: {}", err));
+                Self::default()
+            }
+        }
+    }
+
+    /// Save history using confy
+    pub fn save(&self) -> Result<(), String> {
+        confy::store("kubix", Some("history"), self)
+            .map_err(|err| format!("Failed to save history: {}", err))
+    }
+
+    /// Record a switch from `previous` to `context`
+    pub fn record_context(&mut self, previous: Option<&str>, context: &str) {
+        record(&mut self.contexts, previous, context);
+    }
+
+    /// The most recent context that differs from the current one (for `kubix ctx -`)
+    pub fn previous_context(&self, current: Option<&str>) -> Option<String> {
+        previous(&self.contexts, current)
+    }
+
+    /// Distinct contexts, most recently used first
+    pub fn recent_contexts(&self) -> Vec<HistoryEntry> {
+        recent(&self.contexts)
+    }
+
+    /// Follow a context rename so `kubix ctx -` and namespace history keep working
+    pub fn rename_context(&mut self, old: &str, new: &str) {
+        for entry in self.contexts.iter_mut().filter(|entry| entry.name == old) {
+            entry.name = new.to_string();
+        }
+        if let Some(namespaces) = self.namespaces.remove(old) {
+            self.namespaces.insert(new.to_string(), namespaces);
+        }
+    }
+
+    /// Drop a deleted context from the history
+    pub fn forget_context(&mut self, name: &str) {
+        self.contexts.retain(|entry| entry.name != name);
+        self.namespaces.remove(name);
+    }
+
+    /// Record a default namespace switch within a context
+    pub fn record_namespace(&mut self, context: &str, previous: Option<&str>, namespace: &str) {
+        let entries = self.namespaces.entry(context.to_string()).or_default();
+        record(entries, previous, namespace);
+    }
+
+    /// The most recent namespace of a context that differs from the current one (for `kubix ns -`)
+    pub fn previous_namespace(&self, context: &str, current: Option<&str>) -> Option<String> {
+        self.namespaces
+            .get(context)
+            .and_then(|entries| previous(entries, current))
+    }
+}
+
+/// Append a switch to a history list, remembering where we came from
+fn record(entries: &mut Vec<HistoryEntry>, previous: Option<&str>, name: &str) {
+    // Make sure the starting point is known, so the first `-` can jump back to it
+    if let Some(previous) = previous
+        && previous != name
+        && entries.last().is_none_or(|entry| entry.name != previous) {
+        entries.push(HistoryEntry {
+            name: previous.to_string(),
+            timestamp: time::now_rfc3339(),
+        });
+    }
+
+    entries.push(HistoryEntry {
+        name: name.to_string(),
+        timestamp: time::now_rfc3339(),
+    });
+
+    if entries.len() > MAX_ENTRIES {
+        entries.drain(..entries.len() - MAX_ENTRIES);
+    }
+}
+
+/// The most recent entry name that differs from `current`
+fn previous(entries: &[HistoryEntry], current: Option<&str>) -> Option<String> {
+    entries
+        .iter()
+        .rev()
+        .find(|entry| Some(entry.name.as_str()) != current)
+        .map(|entry| entry.name.clone())
+}
+
+/// Distinct entries, most recent first
+fn recent(entries: &[HistoryEntry]) -> Vec<HistoryEntry> {
+    let mut seen = Vec::new();
+    for entry in entries.iter().rev() {
+        if !seen.iter().any(|seen: &HistoryEntry| seen.name == entry.name) {
+            seen.push(entry.clone());
+        }
+    }
+    seen
+}
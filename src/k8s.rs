@@ -0,0 +1,465 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+// Kubernetes object models parsed from `kubectl ... -o json` output.
+// Only the fields kubix uses are modelled; everything is optional so partial objects still parse.
+
+/// Common object metadata
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ObjectMeta {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub creation_timestamp: Option<String>,
+    pub deletion_timestamp: Option<String>,
+    /// Incremented on every change to the spec
+    pub generation: Option<i64>,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub owner_references: Vec<OwnerReference>,
+}
+
+impl ObjectMeta {
+    /// "namespace/name" - names are only unique within a namespace
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.namespace.as_deref().unwrap_or_default(), self.name)
+    }
+}
+
+/// The object that manages this one (e.g. the ReplicaSet of a pod)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct OwnerReference {
+    pub kind: String,
+    pub name: String,
+}
+
+/// A list of pods (`kubectl get pods -o json`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PodList {
+    pub items: Vec<Pod>,
+}
+
+/// A pod
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Pod {
+    pub metadata: ObjectMeta,
+    pub spec: PodSpec,
+    pub status: PodStatus,
+}
+
+/// Desired state of a pod
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PodSpec {
+    pub node_name: Option<String>,
+    pub containers: Vec<Container>,
+    pub volumes: Vec<Volume>,
+}
+
+/// A volume of a pod spec
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Volume {
+    pub name: String,
+    /// The volume source, keyed by its kind (e.g. configMap, secret, persistentVolumeClaim, emptyDir)
+    #[serde(flatten)]
+    pub source: BTreeMap<String, serde_json::Value>,
+}
+
+/// A container of a pod spec
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Container {
+    pub name: String,
+    pub image: String,
+    pub resources: ResourceRequirements,
+    pub volume_mounts: Vec<VolumeMount>,
+    pub liveness_probe: Option<Probe>,
+    pub readiness_probe: Option<Probe>,
+    pub startup_probe: Option<Probe>,
+}
+
+/// Where a container mounts a volume
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct VolumeMount {
+    pub name: String,
+    pub mount_path: String,
+    pub read_only: bool,
+}
+
+/// A liveness, readiness or startup probe - at most one of the handlers is set
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Probe {
+    pub http_get: Option<HttpGetAction>,
+    pub tcp_socket: Option<PortAction>,
+    pub grpc: Option<PortAction>,
+    pub exec: Option<ExecAction>,
+    pub initial_delay_seconds: u32,
+    pub period_seconds: Option<u32>,
+    pub failure_threshold: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpGetAction {
+    pub path: Option<String>,
+    /// Port number or name
+    pub port: serde_json::Value,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PortAction {
+    /// Port number or name
+    pub port: serde_json::Value,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ExecAction {
+    pub command: Vec<String>,
+}
+
+/// Resource requests and limits of a container, as quantities (e.g. cpu: 250m, memory: 128Mi)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ResourceRequirements {
+    pub requests: BTreeMap<String, String>,
+    pub limits: BTreeMap<String, String>,
+}
+
+/// Current resource usage of a pod from metrics-server (`kubectl get pods.metrics.k8s.io`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PodMetrics {
+    pub metadata: ObjectMeta,
+    pub containers: Vec<ContainerMetrics>,
+}
+
+/// Current resource usage of a container
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ContainerMetrics {
+    pub name: String,
+    pub usage: BTreeMap<String, String>,
+}
+
+/// A list of pod metrics
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PodMetricsList {
+    pub items: Vec<PodMetrics>,
+}
+
+/// Observed state of a pod
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PodStatus {
+    pub phase: Option<String>,
+    pub reason: Option<String>,
+    #[serde(rename = "podIP")]
+    pub pod_ip: Option<String>,
+    pub qos_class: Option<String>,
+    pub conditions: Vec<PodCondition>,
+    pub container_statuses: Vec<ContainerStatus>,
+}
+
+/// A pod condition such as Ready or PodScheduled
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PodCondition {
+    #[serde(rename = "type")]
+    pub condition_type: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+/// Observed state of a single container in a pod
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContainerStatus {
+    pub name: String,
+    pub ready: bool,
+    pub restart_count: u32,
+    pub state: ContainerState,
+    pub last_state: ContainerState,
+}
+
+/// Container state - at most one of the variants is set
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ContainerState {
+    pub running: Option<ContainerStateRunning>,
+    pub waiting: Option<ContainerStateWaiting>,
+    pub terminated: Option<ContainerStateTerminated>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContainerStateRunning {
+    pub started_at: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ContainerStateWaiting {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContainerStateTerminated {
+    pub exit_code: i32,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+impl Pod {
+    /// Status as `kubectl get pods` shows it - container problems win over the pod phase
+    pub fn display_status(&self) -> String {
+        if self.metadata.deletion_timestamp.is_some() {
+            return "Terminating".to_string();
+        }
+        let container_reason = self.status.container_statuses.iter().find_map(|status| {
+            let state = &status.state;
+            state.waiting.as_ref().and_then(|waiting| waiting.reason.clone())
+                .or_else(|| state.terminated.as_ref().and_then(|terminated| terminated.reason.clone()))
+        });
+        container_reason
+            .or_else(|| self.status.reason.clone())
+            .or_else(|| self.status.phase.clone())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// Number of ready containers and total containers
+    pub fn ready_containers(&self) -> (usize, usize) {
+        let ready = self.status.container_statuses.iter().filter(|status| status.ready).count();
+        let total = self.spec.containers.len().max(self.status.container_statuses.len());
+        (ready, total)
+    }
+
+    /// Restarts summed over all containers
+    pub fn restart_count(&self) -> u32 {
+        self.status.container_statuses.iter().map(|status| status.restart_count).sum()
+    }
+
+    /// Whether the pod reports the Ready condition
+    pub fn is_ready(&self) -> bool {
+        self.status
+            .conditions
+            .iter()
+            .any(|condition| condition.condition_type == "Ready" && condition.status == "True")
+    }
+
+    /// The pod's owner as "Kind/name", if it has one
+    pub fn owner(&self) -> Option<String> {
+        self.metadata
+            .owner_references
+            .first()
+            .map(|owner| format!("{}/{}", owner.kind, owner.name))
+    }
+}
+
+/// A deployment (`kubectl get deployment -o json`)
+/// StatefulSets have the same replica fields, so they are read into this model too
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Deployment {
+    pub metadata: ObjectMeta,
+    pub spec: DeploymentSpec,
+    pub status: DeploymentStatus,
+}
+
+/// Desired state of a deployment
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DeploymentSpec {
+    pub replicas: Option<u32>,
+    pub template: PodTemplateSpec,
+}
+
+/// Pod template of a deployment or replica set
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PodTemplateSpec {
+    pub spec: PodSpec,
+}
+
+/// Observed state of a deployment
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeploymentStatus {
+    pub observed_generation: Option<i64>,
+    pub replicas: u32,
+    pub updated_replicas: u32,
+    pub ready_replicas: u32,
+    pub available_replicas: u32,
+    pub unavailable_replicas: u32,
+    pub conditions: Vec<DeploymentCondition>,
+}
+
+/// A deployment condition such as Available or Progressing
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DeploymentCondition {
+    #[serde(rename = "type")]
+    pub condition_type: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+/// A replica set - one per revision of a deployment, with the same spec and status fields kubix reads
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ReplicaSet {
+    pub metadata: ObjectMeta,
+    pub spec: DeploymentSpec,
+    pub status: DeploymentStatus,
+}
+
+/// A list of replica sets (`kubectl get replicasets -o json`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ReplicaSetList {
+    pub items: Vec<ReplicaSet>,
+}
+
+/// Annotation holding the revision of a deployment and of each of its replica sets
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+impl Deployment {
+    /// Number of pods the deployment asks for (1 when unset)
+    pub fn desired_replicas(&self) -> u32 {
+        self.spec.replicas.unwrap_or(1)
+    }
+
+    /// Current revision of the deployment
+    pub fn revision(&self) -> Option<u64> {
+        revision_of(&self.metadata)
+    }
+
+    /// A condition of the deployment by type
+    pub fn condition(&self, condition_type: &str) -> Option<&DeploymentCondition> {
+        self.status.conditions.iter().find(|condition| condition.condition_type == condition_type)
+    }
+}
+
+impl ReplicaSet {
+    /// Deployment revision this replica set belongs to
+    pub fn revision(&self) -> Option<u64> {
+        revision_of(&self.metadata)
+    }
+
+    /// Whether the replica set is owned by the named deployment
+    pub fn is_owned_by(&self, deployment: &str) -> bool {
+        self.metadata
+            .owner_references
+            .iter()
+            .any(|owner| owner.kind == "Deployment" && owner.name == deployment)
+    }
+}
+
+/// Revision annotation of a deployment or replica set
+fn revision_of(metadata: &ObjectMeta) -> Option<u64> {
+    metadata.annotations.get(REVISION_ANNOTATION).and_then(|revision| revision.parse().ok())
+}
+
+/// A cluster event (`kubectl get events -o json`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Event {
+    pub metadata: ObjectMeta,
+    /// Normal or Warning
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub reason: String,
+    pub message: String,
+    pub count: Option<u32>,
+    pub first_timestamp: Option<String>,
+    pub last_timestamp: Option<String>,
+    pub event_time: Option<String>,
+    pub series: Option<EventSeries>,
+    pub involved_object: ObjectReference,
+}
+
+/// Repeats of an event reported through the newer events API
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EventSeries {
+    pub count: u32,
+    pub last_observed_time: Option<String>,
+}
+
+/// The object an event is about
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ObjectReference {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+}
+
+/// A list of events
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct EventList {
+    pub items: Vec<Event>,
+}
+
+impl Event {
+    /// When the event was last seen - events carry this in different fields depending on the API that wrote them
+    pub fn last_seen(&self) -> Option<&str> {
+        self.series
+            .as_ref()
+            .and_then(|series| series.last_observed_time.as_deref())
+            .or(self.last_timestamp.as_deref())
+            .or(self.event_time.as_deref())
+            .or(self.first_timestamp.as_deref())
+            .or(self.metadata.creation_timestamp.as_deref())
+    }
+
+    /// "namespace/name" of the object the event is about, like `ObjectMeta::key`
+    pub fn object_key(&self) -> String {
+        let namespace = self.involved_object.namespace.as_deref().or(self.metadata.namespace.as_deref());
+        format!("{}/{}", namespace.unwrap_or_default(), self.involved_object.name)
+    }
+
+    /// Whether this is a Warning event
+    pub fn is_warning(&self) -> bool {
+        self.event_type == "Warning"
+    }
+
+    /// How often the event occurred
+    pub fn occurrences(&self) -> u32 {
+        self.series
+            .as_ref()
+            .map(|series| series.count)
+            .or(self.count)
+            .unwrap_or(1)
+            .max(1)
+    }
+}
+
+/// An event of a watch stream (`kubectl get ... --watch --output-watch-events -o json`)
+#[derive(Debug, Deserialize)]
+pub struct WatchEvent<T> {
+    /// ADDED, MODIFIED, DELETED, BOOKMARK or ERROR
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub object: T,
+}
+
+/// Parse a kubectl JSON document into a model
+pub fn parse<T: for<'de> Deserialize<'de>>(json: &str, what: &str) -> Result<T, String> {
+    serde_json::from_str(json).map_err(|e| format!("Failed to parse {}: {}", what, e))
+}
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use crate::kubectl;
+use std::path::PathBuf;
+
+/// Merged kubeconfig as reported by `kubectl config view -o json`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct KubeConfig {
+    pub current_context: String,
+    pub contexts: Vec<NamedContext>,
+    pub clusters: Vec<NamedCluster>,
+    pub users: Vec<NamedUser>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct NamedContext {
+    pub name: String,
+    pub context: ContextEntry,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ContextEntry {
+    pub cluster: String,
+    pub user: String,
+    pub namespace: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct NamedCluster {
+    pub name: String,
+    pub cluster: ClusterEntry,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ClusterEntry {
+    pub server: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct NamedUser {
+    pub name: String,
+    pub user: UserEntry,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct UserEntry {
+    /// Credential plugin (e.g. aws, gke-gcloud-auth-plugin, kubelogin)
+    pub exec: Option<ExecConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ExecConfig {
+    pub command: String,
+}
+
+impl KubeConfig {
+    /// Load the merged kubeconfig through kubectl (honours KUBECONFIG and session files)
+    pub fn load() -> Result<Self, String> {
+        let output = kubectl::execute_kubectl(&["config", "view", "-o", "json"])?;
+        serde_json::from_str(&output).map_err(|e| format!("Failed to parse kubeconfig: {}", e))
+    }
+
+    /// Load the merged kubeconfig of specific files
+    pub fn load_from(paths: &[PathBuf]) -> Result<Self, String> {
+        let output = kubectl::execute_with_kubeconfig(&["config", "view", "-o", "json"], paths)?;
+        serde_json::from_str(&output).map_err(|e| format!("Failed to parse kubeconfig: {}", e))
+    }
+
+    /// Find a context entry by exact name
+    pub fn context(&self, name: &str) -> Option<&ContextEntry> {
+        self.contexts
+            .iter()
+            .find(|context| context.name == name)
+            .map(|context| &context.context)
+    }
+
+    /// Find a user entry by exact name
+    pub fn user(&self, name: &str) -> Option<&UserEntry> {
+        self.users
+            .iter()
+            .find(|user| user.name == name)
+            .map(|user| &user.user)
+    }
+
+    /// Clusters no context refers to
+    pub fn orphaned_clusters(&self) -> Vec<String> {
+        self.clusters
+            .iter()
+            .filter(|named| !self.contexts.iter().any(|context| context.context.cluster == named.name))
+            .map(|named| named.name.clone())
+            .collect()
+    }
+
+    /// Users no context refers to
+    pub fn orphaned_users(&self) -> Vec<String> {
+        self.users
+            .iter()
+            .filter(|named| !self.contexts.iter().any(|context| context.context.user == named.name))
+            .map(|named| named.name.clone())
+            .collect()
+    }
+
+    /// API server URL of a cluster by name
+    pub fn server(&self, cluster: &str) -> Option<&str> {
+        self.clusters
+            .iter()
+            .find(|named| named.name == cluster)
+            .map(|named| named.cluster.server.as_str())
+    }
+}
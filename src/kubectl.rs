@@ -1,4 +1,6 @@
-use std::process::Command;
+use std::env;
+use std::path::PathBuf;
+use std::process::{Command, Output};
 
 /// Build kubectl command args with optional context and namespace
 pub fn build_args(
@@ -35,18 +37,46 @@ pub fn execute_kubectl(args: &[&str]) -> Result<String, String> {
     }
 }
 
-/// Execute a kubectl command with interactive mode (for bash, etc.)
-pub fn execute_kubectl_interactive(args: &[&str]) -> bool {
-    let status = Command::new("kubectl")
+/// Execute a kubectl command against specific kubeconfig files (sets KUBECONFIG, first file wins on conflicts)
+pub fn execute_with_kubeconfig(args: &[&str], kubeconfig: &[PathBuf]) -> Result<String, String> {
+    let kubeconfig = env::join_paths(kubeconfig)
+        .map_err(|e| format!("Invalid kubeconfig path: {}", e))?;
+    let output = Command::new("kubectl")
         .args(args)
-        .status();
+        .env("KUBECONFIG", kubeconfig)
+        .output()
+        .map_err(|e| format!("Failed to execute kubectl: {}", e))?;
 
-    match status {
-        Ok(exit_status) => exit_status.success(),
-        Err(_) => false,
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
     }
 }
 
+/// Execute a kubectl command with interactive mode (for bash, etc.) and return its exit code
+/// Returns None if kubectl could not be started or was killed by a signal
+pub fn execute_kubectl_interactive(args: &[&str]) -> Option<i32> {
+    Command::new("kubectl")
+        .args(args)
+        .status()
+        .ok()
+        .and_then(|exit_status| exit_status.code())
+}
+
+/// Execute a kubectl command with context and namespace support, returning the raw output with exit status
+pub fn execute_output_with_context(
+    base_args: &[&str],
+    context: Option<&str>,
+    namespace: Option<&str>
+) -> Result<Output, String> {
+    let args = build_args(base_args, context, namespace);
+    Command::new("kubectl")
+        .args(&args)
+        .output()
+        .map_err(|e| format!("Failed to execute kubectl: {}", e))
+}
+
 /// Execute a kubectl command with context and namespace support
 pub fn execute_with_context(
     base_args: &[&str],
@@ -58,12 +88,12 @@ pub fn execute_with_context(
     execute_kubectl(&args_refs)
 }
 
-/// Execute an interactive kubectl command with context and namespace support
+/// Execute an interactive kubectl command with context and namespace support, returning its exit code
 pub fn execute_interactive_with_context(
     base_args: &[&str],
     context: Option<&str>,
     namespace: Option<&str>
-) -> bool {
+) -> Option<i32> {
     let args = build_args(base_args, context, namespace);
     let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
     execute_kubectl_interactive(&args_refs)
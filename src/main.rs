@@ -3,16 +3,37 @@ mod utils;
 mod kubectl;
 mod commands;
 mod display;
+mod time;
+mod k8s;
+mod history;
+mod kubeconfig;
+mod session;
+mod audit;
+mod cache;
+mod quantity;
 
 use clap::Parser;
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, CacheCommands, RolloutCommands};
 use commands::{
     handle_ctx_command, 
+    handle_ctx_subcommand,
+    handle_ns_command,
     handle_pods_command, 
     handle_exec_command, 
     handle_config_command,
-    handle_logs_command
+    handle_logs_command,
+    handle_audit_command,
+    handle_doctor_command,
+    handle_describe_command,
+    handle_events_command,
+    handle_delete_command,
+    handle_restart_command,
+    handle_rollout_command,
+    handle_rollout_undo_command,
+    handle_scale_command
 };
+use commands::logs::LogsOptions;
+use commands::pods::{PodSelectors, PodsOptions};
 
 fn main() {
     // Setup signal handling for graceful cancellation
@@ -22,55 +43,145 @@ fn main() {
     }).expect("Error setting Ctrl+C handler");
 
     let cli: Cli = Cli::parse();
+    if cli.no_cache {
+        cache::disable();
+    }
+    // Leaving a session or starting a new one must work even when the session file is gone
+    if !matches!(cli.command, Commands::Ctx { exit_shell: true, .. } | Commands::Ctx { shell: true, .. }) {
+        session::check_active_session();
+    }
     handle_command(&cli.command);
 }
 
 fn handle_command(command: &Commands) {
     match command {
-        Commands::Ctx { name } => {
-            handle_ctx_command(name.as_deref());
+        Commands::Ctx { command: Some(command), .. } => {
+            handle_ctx_subcommand(command);
+        }
+        Commands::Ctx { command: None, name, history, shell, exit_shell } => {
+            handle_ctx_command(name.as_deref(), *history, *shell, *exit_shell);
+        }
+        Commands::Ns { name } => {
+            handle_ns_command(name.as_deref());
         }
-        Commands::PodsList { pattern, context, namespace } => {
-            handle_pods_command(pattern.as_deref(), context.as_deref(), namespace.as_deref());
+        Commands::PodsList { pattern, context, contexts, namespace, all_namespaces, selector, field_selector, wide, sort, status, not_ready, watch, usage }
+        | Commands::Pod { pattern, context, contexts, namespace, all_namespaces, selector, field_selector, wide, sort, status, not_ready, watch, usage } => {
+            let options = PodsOptions {
+                selectors: PodSelectors {
+                    labels: selector.as_deref(),
+                    fields: field_selector.as_deref(),
+                },
+                all_namespaces: *all_namespaces,
+                wide: *wide,
+                sort: sort.as_deref(),
+                statuses: status,
+                not_ready: *not_ready,
+                watch: *watch,
+                usage: *usage,
+            };
+            handle_pods_command(pattern.as_deref(), context.as_deref(), contexts.as_deref(), namespace.as_deref(), &options);
         }
-        Commands::Pod { pattern, context, namespace } => {
-            handle_pods_command(pattern.as_deref(), context.as_deref(), namespace.as_deref());
+        Commands::Top { pattern, context, contexts, namespace, all_namespaces, selector, field_selector, sort } => {
+            let options = PodsOptions {
+                selectors: PodSelectors {
+                    labels: selector.as_deref(),
+                    fields: field_selector.as_deref(),
+                },
+                all_namespaces: *all_namespaces,
+                wide: false,
+                sort: Some(sort),
+                statuses: &[],
+                not_ready: false,
+                watch: false,
+                usage: true,
+            };
+            handle_pods_command(pattern.as_deref(), context.as_deref(), contexts.as_deref(), namespace.as_deref(), &options);
         }
-        Commands::Exec { pod, command, script, context, namespace } => {
+        Commands::Exec { pod, selector, field_selector, command, script, context, contexts, namespace, all_namespaces } => {
+            let selectors = PodSelectors {
+                labels: selector.as_deref(),
+                fields: field_selector.as_deref(),
+            };
             handle_exec_command(
-                pod, 
+                pod.as_deref().unwrap_or_default(), 
+                &selectors,
                 command.as_deref(), 
                 script.as_deref(), 
                 context.as_deref(), 
-                namespace.as_deref()
+                contexts.as_deref(),
+                namespace.as_deref(),
+                *all_namespaces,
             );
         }
-        Commands::Log { pod, context, namespace, follow, tail, previous, container, grep, exclude } => {
+        Commands::Log { pod, selector, field_selector, all_pods, context, namespace, all_namespaces, follow, tail, previous, container, grep, exclude, stats, top }
+        | Commands::Logs { pod, selector, field_selector, all_pods, context, namespace, all_namespaces, follow, tail, previous, container, grep, exclude, stats, top } => {
+            let options = LogsOptions {
+                follow: *follow,
+                tail: *tail,
+                previous: *previous,
+                container: container.as_deref(),
+                grep: grep.as_deref(),
+                exclude: exclude.as_deref(),
+                stats: *stats,
+                top: *top,
+                all_pods: *all_pods,
+                all_namespaces: *all_namespaces,
+            };
+            let selectors = PodSelectors {
+                labels: selector.as_deref(),
+                fields: field_selector.as_deref(),
+            };
             handle_logs_command(
-                pod,
+                pod.as_deref(),
+                &selectors,
                 context.as_deref(),
                 namespace.as_deref(),
-                *follow,
-                *tail,
-                *previous,
-                container.as_deref(),
-                grep.as_deref(),
-                exclude.as_deref()
+                &options
             );
         }
-        Commands::Logs { pod, context, namespace, follow, tail, previous, container, grep, exclude } => {
-            handle_logs_command(
-                pod,
+        Commands::Audit { since, context, pod, limit } => {
+            handle_audit_command(since.as_deref(), context.as_deref(), pod.as_deref(), *limit);
+        }
+        Commands::Describe { pod, selector, field_selector, context, namespace, all_namespaces } => {
+            let selectors = PodSelectors {
+                labels: selector.as_deref(),
+                fields: field_selector.as_deref(),
+            };
+            handle_describe_command(
+                pod.as_deref().unwrap_or_default(),
+                &selectors,
                 context.as_deref(),
                 namespace.as_deref(),
-                *follow,
-                *tail,
-                *previous,
-                container.as_deref(),
-                grep.as_deref(),
-                exclude.as_deref()
+                *all_namespaces,
             );
         }
+        Commands::Events { pattern, context, namespace, all_namespaces, watch } => {
+            handle_events_command(pattern.as_deref(), context.as_deref(), namespace.as_deref(), *all_namespaces, *watch);
+        }
+        Commands::Delete { pattern, context, namespace, dry_run } => {
+            handle_delete_command(pattern, context.as_deref(), namespace.as_deref(), *dry_run);
+        }
+        Commands::Restart { pattern, context, namespace, dry_run } => {
+            handle_restart_command(pattern, context.as_deref(), namespace.as_deref(), *dry_run);
+        }
+        Commands::Scale { pattern, replicas, context, namespace, wait, dry_run } => {
+            handle_scale_command(pattern, replicas, context.as_deref(), namespace.as_deref(), *wait, *dry_run);
+        }
+        Commands::Rollout { command: Some(RolloutCommands::Undo { pattern, to_revision, context, namespace, dry_run }), .. } => {
+            handle_rollout_undo_command(pattern, *to_revision, context.as_deref(), namespace.as_deref(), *dry_run);
+        }
+        Commands::Rollout { command: None, pattern, context, namespace, history } => {
+            handle_rollout_command(pattern.as_deref().unwrap_or_default(), context.as_deref(), namespace.as_deref(), *history);
+        }
+        Commands::Doctor { context } => {
+            handle_doctor_command(context.as_deref());
+        }
+        Commands::Cache { command: CacheCommands::Clear } => {
+            match cache::clear() {
+                Ok(count) => display::print_success(&format!("Cleared {} cache entries", count)),
+                Err(err) => display::print_error_and_exit(&err),
+            }
+        }
         Commands::Config { command } => {
             handle_config_command(command.as_ref());
         }
@@ -0,0 +1,65 @@
+/// Parse a Kubernetes CPU quantity (e.g. "250m", "1.5", "123456n") into millicores
+/// Returns None if the quantity is malformed
+pub fn parse_cpu_millis(quantity: &str) -> Option<f64> {
+    let quantity = quantity.trim();
+    let (number, scale) = match quantity.char_indices().last()? {
+        (i, 'n') => (&quantity[..i], 1e-6),
+        (i, 'u') => (&quantity[..i], 1e-3),
+        (i, 'm') => (&quantity[..i], 1.0),
+        _ => (quantity, 1000.0),
+    };
+    number.parse::<f64>().ok().filter(|value| value.is_finite()).map(|value| value * scale)
+}
+
+/// Parse a Kubernetes memory quantity (e.g. "128Mi", "1G", "524288Ki", "1e9") into bytes
+/// Returns None if the quantity is malformed
+pub fn parse_memory_bytes(quantity: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 13] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+        ("m", 1e-3),
+    ];
+
+    let quantity = quantity.trim();
+    let (number, scale) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, scale)| quantity.strip_suffix(suffix).map(|number| (number, *scale)))
+        .unwrap_or((quantity, 1.0));
+    number.parse::<f64>().ok().filter(|value| value.is_finite()).map(|value| value * scale)
+}
+
+/// Format millicores the way `kubectl top` does (e.g. "250m")
+pub fn format_cpu_millis(millis: f64) -> String {
+    format!("{}m", millis.round() as u64)
+}
+
+/// Format bytes with the largest binary unit that keeps the number readable (e.g. "96Mi", "1.5Gi")
+pub fn format_memory_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["Ki", "Mi", "Gi", "Ti"];
+
+    let mut value = bytes;
+    let mut unit = None;
+    for candidate in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = Some(candidate);
+    }
+
+    match unit {
+        None => format!("{}", value.round() as u64),
+        Some(unit) if value < 10.0 && value.fract() >= 0.05 => format!("{:.1}{}", value, unit),
+        Some(unit) => format!("{}{}", value.round() as u64, unit),
+    }
+}
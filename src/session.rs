@@ -0,0 +1,187 @@
+use crate::{kubectl, display, time};
+use crate::commands::config::KubixConfig;
+use crate::kubeconfig::KubeConfig;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Environment variable pointing at the active session kubeconfig
+pub const SESSION_ENV: &str = "KUBIX_SESSION";
+
+/// Session kubeconfigs untouched for this long are removed when a new session starts
+/// Every kubix command run in a session touches its file, so only abandoned sessions get this old
+const SESSION_MAX_AGE_DAYS: u64 = 7;
+
+/// The session kubeconfig of the current shell, if a session is active
+pub fn active_session() -> Option<PathBuf> {
+    env::var_os(SESSION_ENV)
+        .map(PathBuf::from)
+        .filter(|path| path.is_file())
+}
+
+/// Check the session of the current shell before running a command
+/// Its file is touched so it is not pruned while in use; if it is gone, stop -
+/// kubectl would otherwise silently fall back to the shared current context
+pub fn check_active_session() {
+    let Some(session_file) = env::var_os(SESSION_ENV).map(PathBuf::from) else {
+        return;
+    };
+    if session_file.is_file() {
+        let touched = fs::File::options()
+            .append(true)
+            .open(&session_file)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+        if let Err(e) = touched {
+            display::print_warning(&format!("Failed to refresh {}: {}", session_file.display(), e));
+        }
+        return;
+    }
+    display::print_error_and_exit(&format!(
+        "The session kubeconfig of this shell is gone ({}) - kubectl would use the shared current context. \
+         Leave the session with `eval \"$(kubix ctx --exit-shell)\"` or start a new one with `kubix ctx <name> --shell`",
+        session_file.display()
+    ));
+}
+
+/// Point the session kubeconfig at a context, copying its cluster, user and namespace
+pub fn set_session_context(session_file: &Path, context: &str) -> Result<(), String> {
+    let kubeconfig = KubeConfig::load()?;
+    let entry = kubeconfig
+        .context(context)
+        .ok_or_else(|| format!("Context '{}' not found in kubeconfig", context))?;
+
+    let session_path = session_file.to_string_lossy().to_string();
+    let cluster_arg = format!("--cluster={}", entry.cluster);
+    let user_arg = format!("--user={}", entry.user);
+    let mut args = vec!["config", "--kubeconfig", &session_path, "set-context", context, &cluster_arg, &user_arg];
+
+    let namespace_arg = entry.namespace.as_ref().map(|ns| format!("--namespace={}", ns));
+    if let Some(namespace_arg) = &namespace_arg {
+        args.push(namespace_arg);
+    }
+
+    kubectl::execute_kubectl(&args)?;
+    kubectl::execute_kubectl(&["config", "--kubeconfig", &session_path, "use-context", context])?;
+    Ok(())
+}
+
+/// Change the default namespace of the session's context
+pub fn set_session_namespace(session_file: &Path, namespace: &str) -> Result<(), String> {
+    let session_path = session_file.to_string_lossy().to_string();
+    let namespace_arg = format!("--namespace={}", namespace);
+    kubectl::execute_kubectl(&["config", "--kubeconfig", &session_path, "set-context", "--current", &namespace_arg])?;
+    Ok(())
+}
+
+/// Start (or reuse) the shell session and switch it to a context
+/// Returns the session kubeconfig path
+pub fn enter_session(context: &str) -> Result<PathBuf, String> {
+    let session_file = match active_session() {
+        Some(path) => path,
+        None => create_session_file()?,
+    };
+    set_session_context(&session_file, context)?;
+    Ok(session_file)
+}
+
+/// Print the shell exports that activate a session kubeconfig
+pub fn print_session_exports(session_file: &Path) {
+    let session_path = session_file.to_string_lossy().to_string();
+    let mut paths = vec![session_file.to_path_buf()];
+    paths.extend(base_kubeconfig_paths());
+
+    let kubeconfig = env::join_paths(&paths)
+        .map(|joined| joined.to_string_lossy().to_string())
+        .unwrap_or_else(|_| session_path.clone());
+
+    display::print_stdout_line(&format!("export {}={}", SESSION_ENV, shell_quote(&session_path)));
+    display::print_stdout_line(&format!("export KUBECONFIG={}", shell_quote(&kubeconfig)));
+}
+
+/// Print the shell commands that leave the session and restore the shared kubeconfig
+pub fn print_exit_exports() {
+    let base = base_kubeconfig_paths();
+    if env::var_os("KUBECONFIG").is_none() || base == [default_kubeconfig_path()] {
+        display::print_stdout_line("unset KUBECONFIG");
+    } else {
+        let kubeconfig = env::join_paths(&base)
+            .map(|joined| joined.to_string_lossy().to_string())
+            .unwrap_or_default();
+        display::print_stdout_line(&format!("export KUBECONFIG={}", shell_quote(&kubeconfig)));
+    }
+    display::print_stdout_line(&format!("unset {}", SESSION_ENV));
+
+    if let Some(session_file) = active_session() {
+        let _ = fs::remove_file(session_file);
+    }
+}
+
+/// Kubeconfig files shared by all shells: KUBECONFIG without session files, or the default path
+pub fn base_kubeconfig_paths() -> Vec<PathBuf> {
+    let sessions_dir = sessions_dir();
+    let paths: Vec<PathBuf> = env::var_os("KUBECONFIG")
+        .map(|value| env::split_paths(&value).collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| !path.as_os_str().is_empty())
+        .filter(|path| sessions_dir.as_ref().is_none_or(|dir| !path.starts_with(dir)))
+        .collect();
+
+    if paths.is_empty() {
+        vec![default_kubeconfig_path()]
+    } else {
+        paths
+    }
+}
+
+/// The default kubeconfig location (~/.kube/config)
+pub fn default_kubeconfig_path() -> PathBuf {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    home.join(".kube").join("config")
+}
+
+/// Directory holding per-shell session kubeconfigs
+fn sessions_dir() -> Option<PathBuf> {
+    KubixConfig::get_config_dir().map(|dir| dir.join("sessions"))
+}
+
+/// Create a new, empty session kubeconfig, pruning stale ones
+fn create_session_file() -> Result<PathBuf, String> {
+    let dir = sessions_dir().ok_or("Could not determine the kubix config directory")?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    prune_stale_sessions(&dir);
+
+    let path = dir.join(format!("session-{}-{}.yaml", time::now_seconds(), std::process::id()));
+    fs::write(&path, "apiVersion: v1\nkind: Config\n")
+        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Remove session kubeconfigs that have not been touched for a while
+fn prune_stale_sessions(dir: &Path) {
+    let max_age = std::time::Duration::from_secs(SESSION_MAX_AGE_DAYS * 86400);
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let is_stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > max_age);
+        if is_stale {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Quote a value for POSIX shells
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
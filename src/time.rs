@@ -0,0 +1,158 @@
+/// Parse an RFC3339 timestamp (as printed by kubectl) into seconds and nanoseconds since the Unix epoch
+/// Returns None if the timestamp is malformed
+pub fn parse_rfc3339(timestamp: &str) -> Option<(i64, u32)> {
+    let timestamp = timestamp.trim();
+    if timestamp.len() < 20 || !timestamp.is_char_boundary(10) || !timestamp.is_char_boundary(19) {
+        return None;
+    }
+
+    let (date, rest) = timestamp.split_at(10);
+    let mut date_parts = date.split('-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+
+    if !rest.starts_with(['T', 't', ' ']) {
+        return None;
+    }
+    let (clock, mut zone) = rest[1..].split_at(8);
+    let mut clock_parts = clock.split(':');
+    let hour: i64 = clock_parts.next()?.parse().ok()?;
+    let minute: i64 = clock_parts.next()?.parse().ok()?;
+    let second: i64 = clock_parts.next()?.parse().ok()?;
+
+    // Optional fractional seconds, normalised to nanoseconds
+    let mut nanos = 0u32;
+    if let Some(fraction) = zone.strip_prefix('.') {
+        let digits_len = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+        let digits = &fraction[..digits_len.min(9)];
+        if digits.is_empty() {
+            return None;
+        }
+        nanos = format!("{:0<9}", digits).parse().ok()?;
+        zone = &fraction[digits_len..];
+    }
+
+    let offset_seconds = match zone {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match zone.chars().next()? {
+                '+' => 1,
+                '-' => -1,
+                _ => return None,
+            };
+            let (offset_hours, offset_minutes) = zone[1..].split_once(':')?;
+            let offset_hours: i64 = offset_hours.parse().ok()?;
+            let offset_minutes: i64 = offset_minutes.parse().ok()?;
+            sign * (offset_hours * 3600 + offset_minutes * 60)
+        }
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset_seconds;
+    Some((seconds, nanos))
+}
+
+/// Number of days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Format a duration in seconds as a compact human string (e.g. "45s", "3m12s", "2h5m", "4d3h")
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (days, hours, minutes, secs) = (seconds / 86400, seconds % 86400 / 3600, seconds % 3600 / 60, seconds % 60);
+    if days > 0 {
+        format!("{}d{}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h{}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m{}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Seconds elapsed between two RFC3339 timestamps, if both parse
+pub fn seconds_between(start: &str, end: &str) -> Option<i64> {
+    Some(parse_rfc3339(end)?.0 - parse_rfc3339(start)?.0)
+}
+
+/// Current time as an RFC3339 UTC timestamp (second precision)
+pub fn now_rfc3339() -> String {
+    format_rfc3339(now_seconds())
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn now_seconds() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Format seconds since the Unix epoch as an RFC3339 UTC timestamp
+pub fn format_rfc3339(seconds: i64) -> String {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let time_of_day = seconds.rem_euclid(86400);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, time_of_day / 3600, time_of_day % 3600 / 60, time_of_day % 60
+    )
+}
+
+/// Describe how long ago an RFC3339 timestamp was (e.g. "5m12s ago")
+pub fn format_ago(timestamp: &str) -> String {
+    match parse_rfc3339(timestamp) {
+        Some((seconds, _)) => format!("{} ago", format_duration(now_seconds() - seconds)),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Proleptic Gregorian date for a number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = (if days >= 0 { days } else { days - 146096 }) / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = (if month_index < 10 { month_index + 3 } else { month_index - 9 }) as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Parse a relative duration such as "45s", "30m", "2h" or "7d" into seconds
+pub fn parse_duration(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let unit = value.chars().last()?;
+    let multiplier = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 3600,
+        'd' => 86400,
+        'w' => 7 * 86400,
+        _ => return None,
+    };
+    // Unsigned, so negative durations ("-5m") are rejected
+    let amount: u32 = value[..value.len() - 1].parse().ok()?;
+    Some(i64::from(amount) * multiplier)
+}
+
+/// Parse a point in time given as a relative duration ("2h" = two hours ago),
+/// an RFC3339 timestamp, or a date (YYYY-MM-DD) - returns unix seconds
+pub fn parse_since(value: &str) -> Option<i64> {
+    if let Some(seconds) = parse_duration(value) {
+        return Some(now_seconds() - seconds);
+    }
+    if let Some((seconds, _)) = parse_rfc3339(value) {
+        return Some(seconds);
+    }
+    parse_rfc3339(&format!("{}T00:00:00Z", value)).map(|(seconds, _)| seconds)
+}
@@ -29,6 +29,82 @@ pub fn select_from_matches<T: Clone + std::fmt::Display>(
     }
 }
 
+/// Like `select_from_matches`, but lets the user pick several of the matches
+/// Returns None if there are no matches or the user cancels
+pub fn select_multiple_from_matches<T: Clone + std::fmt::Display>(
+    matches: Vec<T>,
+    pattern: &str,
+    resource_type: &str
+) -> Option<Vec<T>> {
+    match matches.len() {
+        0 => {
+            display::print_error(&format!("No {} found matching pattern: '{}'", resource_type, pattern));
+            None
+        }
+        1 => {
+            display::print_success(&format!("Found {}: {}", resource_type, matches[0]));
+            Some(matches)
+        }
+        _ => {
+            display::print_selection_table(&matches, resource_type, None);
+            let choices = prompt_user_choices(matches.len(), resource_type)?;
+            Some(choices.into_iter().map(|index| matches[index].clone()).collect())
+        }
+    }
+}
+
+/// Prompt user to choose several options ("1,3-5", "a" for all) until the input is valid
+fn prompt_user_choices(max_options: usize, resource_type: &str) -> Option<Vec<usize>> {
+    loop {
+        display::print(&format!("\nSelect {}s (e.g. 1,2 or 1-{}, 'a' for all, or 'q' to quit): ", resource_type, max_options));
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        match io::stdin().read_line(&mut input) {
+            // End of input or Ctrl+C cancels
+            Ok(0) | Err(_) => return None,
+            Ok(_) => {}
+        }
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("q") || input.eq_ignore_ascii_case("quit") {
+            return None;
+        }
+        if input.eq_ignore_ascii_case("a") || input.eq_ignore_ascii_case("all") {
+            return Some((0..max_options).collect());
+        }
+
+        match parse_choices(input, max_options) {
+            Some(choices) => return Some(choices),
+            None => display::print_error(&format!(
+                "Invalid selection. Enter numbers or ranges between 1 and {}, 'a' for all or 'q' to quit.", max_options
+            )),
+        }
+    }
+}
+
+/// Parse a selection like "1,3-5" into zero-based indices, without duplicates
+fn parse_choices(input: &str, max_options: usize) -> Option<Vec<usize>> {
+    let mut choices = Vec::new();
+    for part in input.split(',').map(str::trim) {
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (start.trim().parse::<usize>().ok()?, end.trim().parse::<usize>().ok()?),
+            None => {
+                let number = part.parse::<usize>().ok()?;
+                (number, number)
+            }
+        };
+        if start < 1 || end > max_options || start > end {
+            return None;
+        }
+        for number in start..=end {
+            if !choices.contains(&(number - 1)) {
+                choices.push(number - 1);
+            }
+        }
+    }
+    Some(choices)
+}
+
 /// Prompt user to choose from multiple options with retry logic
 fn prompt_user_choice(max_options: usize, resource_type: &str) -> Option<usize> {
     display::print(&format!("\nSelect {} (1-{}, or 'q' to quit): ", resource_type, max_options));
@@ -75,3 +151,52 @@ pub fn prompt_for_confirmation(message: &str) -> bool {
         }
     }
 }
+
+/// Prompt user to type an exact value (e.g. a context name) to confirm a dangerous action
+pub fn prompt_for_typed_confirmation(message: &str, expected: &str) -> bool {
+    display::print(&format!("❓ {} ", message));
+    io::stdout().flush().unwrap();
+    
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(_) => input.trim() == expected,
+        Err(_) => {
+            // Handle Ctrl+C or read error as cancellation
+            false
+        }
+    }
+}
+
+/// Match a name against a pattern: globs (`*`, `?`) must match the whole name,
+/// plain patterns match as a substring like everywhere else in kubix
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    if pattern.contains(['*', '?']) {
+        glob_match(pattern.as_bytes(), name.as_bytes())
+    } else {
+        name.contains(pattern)
+    }
+}
+
+/// Simple glob matching supporting `*` (any run) and `?` (any single character)
+fn glob_match(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    
+    pattern[p..].iter().all(|&c| c == b'*')
+}